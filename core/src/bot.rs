@@ -1,10 +1,7 @@
+use std::sync::Arc;
 use std::time::Duration;
-use std::{borrow::BorrowMut, cell::RefCell, ops::DerefMut, sync::Arc};
 
-use axum::{
-    handler,
-    routing::{get, post},
-};
+use axum::routing::{get, post};
 use axum::{response::IntoResponse, Extension};
 use axum::{Json, Router};
 use serde_json::json;
@@ -12,19 +9,29 @@ use tokio::sync::{mpsc, Mutex};
 use tower_http::cors::{Any, CorsLayer};
 
 use crate::{
+    middleware::Middleware,
     models::{
         allowed_update::AllowedUpdate,
         update::{Update, UpdateContent},
     },
     params::{message_params::SendMessageParams, updates_params::GetUpdatesParamsBuilder},
-    BotCommands, CommandHandler, EventHandler, JsonData, MessageSendLockTime, RateLimitSemaphore,
-    TelegrapherError, TelegrapherResult, UpdateHandler,
+    payments::PaymentFlow,
+    requests::{self, RetryPolicy},
+    BotCommands, ChatMigrationHandler, CommandHandler, EventHandler, JsonData, MessageSendLockTime,
+    RateLimitSemaphore, TelegrapherError, TelegrapherResult, UpdateHandler, TELEGRAM_API_URL,
 };
 
 #[must_use]
 #[derive(Clone)]
 pub struct Bot {
     pub token: Arc<String>,
+    pub api_url: Arc<String>,
+    /// The Bot API server runs with `--local` on this host, see `Bot::with_local_api_server`.
+    pub local_api_server: bool,
+    /// http client shared by every request of this bot, cloning it keeps the same connection pool.
+    pub client: reqwest::Client,
+    pub retry_policy: Arc<RetryPolicy>,
+    pub middlewares: Arc<Vec<Arc<dyn Middleware>>>,
     pub handler: Arc<Mutex<EventHandler>>,
     pub rate_limiter: Arc<RateLimitSemaphore>,
     pub message_send_lock_time: Arc<MessageSendLockTime>,
@@ -42,6 +49,13 @@ impl Bot {
 
         Self {
             token: Arc::new(token.to_string()),
+            api_url: Arc::new(TELEGRAM_API_URL.to_string()),
+            local_api_server: false,
+            client: requests::default_client_builder()
+                .build()
+                .expect("failed to create reqwest::Client"),
+            retry_policy: Arc::new(RetryPolicy::default()),
+            middlewares: Arc::new(Vec::new()),
             handler: Arc::new(Mutex::new(EventHandler::default())),
             rate_limiter: Arc::new(RateLimitSemaphore::default()),
             message_send_lock_time: Arc::new(new_sender_sleep_times),
@@ -56,10 +70,60 @@ impl Bot {
         *message_sender = Some(sender);
     }
 
+    /// Use another Bot API server, e.g. a self-hosted `telegram-bot-api` instance.
+    /// All methods, file downloads and webhook calls are sent to this base url.
+    pub fn with_api_url(mut self, api_url: &str) -> Self {
+        self.api_url = Arc::new(api_url.trim_end_matches('/').to_string());
+        self
+    }
+
+    /// The Bot API server at `api_url` runs in `--local` mode on this host: the absolute file paths it returns
+    /// from `getFile` are read from the local filesystem instead of being downloaded.
+    pub fn with_local_api_server(mut self, local_api_server: bool) -> Self {
+        self.local_api_server = local_api_server;
+        self
+    }
+
+    /// Use a prebuilt http client, e.g. to set a proxy, user agent or pool size.
+    /// `requests::default_client_builder` gives a builder with the default timeouts to start from.
+    pub fn with_client(mut self, client: reqwest::Client) -> Self {
+        self.client = client;
+        self
+    }
+
+    /// Set how failed requests are retried. Clone the bot first to change it for a single call:
+    /// `bot.clone().with_retry_policy(RetryPolicy::none()).edit_message(&params)`.
+    pub fn with_retry_policy(mut self, retry_policy: RetryPolicy) -> Self {
+        self.retry_policy = Arc::new(retry_policy);
+        self
+    }
+
+    /// Add a middleware around every Bot API call, see `Middleware`.
+    pub fn with_middleware(mut self, middleware: impl Middleware + 'static) -> Self {
+        let mut middlewares = self.middlewares.as_ref().clone();
+        middlewares.push(Arc::new(middleware));
+        self.middlewares = Arc::new(middlewares);
+        self
+    }
+
     pub fn token(&self) -> &str {
         &self.token
     }
 
+    pub fn api_url(&self) -> &str {
+        &self.api_url
+    }
+
+    /// url of a bot api method, e.g. `https://api.telegram.org/bot<token>/getMe`
+    pub fn method_url(&self, method: &str) -> String {
+        format!("{}/bot{}/{}", self.api_url, self.token, method)
+    }
+
+    /// url to download a file by the `file_path` returned from `getFile`
+    pub fn file_url(&self, file_path: &str) -> String {
+        format!("{}/file/bot{}/{}", self.api_url, self.token, file_path)
+    }
+
     /// register update handler function.
     pub async fn register_update_handler(&self, handler: UpdateHandler) {
         self.handler.lock().await.register_update_handler(handler);
@@ -73,16 +137,55 @@ impl Bot {
             .register_command_handler::<T>(handler);
     }
 
+    /// register chat migration handler function.
+    /// It is called when a group is migrated to a supergroup, so the stored chat id can be updated.
+    /// Requests sent to the old chat id are resent to the new one automatically.
+    pub async fn register_chat_migration_handler(&self, handler: ChatMigrationHandler) {
+        self.handler
+            .lock()
+            .await
+            .register_chat_migration_handler(handler);
+    }
+
+    /// register the payment flow answering shipping and pre-checkout queries and delivering successful payments.
+    /// Payment updates it handles are not passed to the update handler.
+    pub async fn register_payment_flow(&self, payment_flow: PaymentFlow) {
+        self.handler
+            .lock()
+            .await
+            .register_payment_flow(payment_flow);
+    }
+
+    /// call the chat migration handler in a new task.
+    pub(crate) async fn notify_chat_migration(&self, old_chat_id: i64, new_chat_id: i64) {
+        let handler = self.handler.lock().await.chat_migration_handler;
+        if let Some(handler) = handler {
+            let bot = self.clone();
+            tokio::spawn(async move {
+                if let Err(e) = handler(bot, old_chat_id, new_chat_id).await {
+                    log::error!("chat migration handler failed: {}", e);
+                }
+            });
+        }
+    }
+
     /// Start getting updates from telegram api server.
     pub async fn start(&self) -> Result<(), TelegrapherError> {
         let mut offset = None;
+        let mut allowed_updates = vec![AllowedUpdate::Message, AllowedUpdate::CallbackQuery];
+        if self.handler.lock().await.payment_flow.is_some() {
+            allowed_updates.extend([
+                AllowedUpdate::ShippingQuery,
+                AllowedUpdate::PreCheckoutQuery,
+            ]);
+        }
         loop {
             let params = GetUpdatesParamsBuilder::default()
                 .offset(offset)
                 .timeout(10)
-                .allowed_updates(vec![AllowedUpdate::Message, AllowedUpdate::CallbackQuery])
+                .allowed_updates(allowed_updates.clone())
                 .build();
-            if let Err(_) = params {
+            if params.is_err() {
                 log::error!("failed to build params");
                 continue;
             }
@@ -93,22 +196,14 @@ impl Bot {
                 continue;
             }
 
-            let updates_res = response.unwrap();
-            let result = updates_res.result;
-            match result {
-                Some(mut updates) => {
-                    updates.sort_by(|a, b| a.update_id.cmp(&b.update_id));
-                    for update in updates {
-                        offset = Some(update.update_id + 1);
-                        let bot = Arc::new(self.clone());
-                        tokio::spawn(async move {
-                            _ = bot.process_update(&update.content).await;
-                        });
-                    }
-                }
-                None => {
-                    log::error!("no updates found: {:?}", updates_res.description);
-                }
+            let mut updates = response.unwrap();
+            updates.sort_by_key(|update| update.update_id);
+            for update in updates {
+                offset = Some(update.update_id + 1);
+                let bot = Arc::new(self.clone());
+                tokio::spawn(async move {
+                    _ = bot.process_update(&update.content).await;
+                });
             }
         }
     }
@@ -124,10 +219,8 @@ impl Bot {
         let listener = tokio::net::TcpListener::bind(addr).await?;
         let addr = listener.local_addr().expect("failed to get local addr");
         log::info!("Webhook is running on {}", addr);
-        match axum::serve(listener, app).await {
-            Ok(_) => Ok(()),
-            Err(e) => Err(Box::new(e)),
-        }
+        axum::serve(listener, app).await?;
+        Ok(())
     }
 
     /// Start a message channel monitor to send messages to telegram api server.
@@ -156,16 +249,16 @@ impl Bot {
             let channel_sender = sender.clone();
             tokio::spawn(async move {
                 match bot.send_message_throttled(&params).await {
-                    Ok(response) => {
-                        if response.ok {
-                            return;
-                        }
-                        if let Some(retry_after) = response.parameters.and_then(|p| p.retry_after) {
+                    Ok(_) => {}
+                    Err(TelegrapherError::Api(e)) => {
+                        if let Some(retry_after) = e.retry_after() {
                             {
                                 let mut sleep_time = sleep_time_clone.lock().await;
                                 *sleep_time += retry_after;
                             }
                             _ = channel_sender.send(params.clone()).await;
+                        } else {
+                            log::error!("failed to send message: {}", e);
                         }
                     }
                     Err(e) => {
@@ -186,13 +279,17 @@ impl Bot {
             let user_chat_sem = rate_limiter.acquire_user_chat(chat_id).await;
             let user_chat_permit = user_chat_sem.acquire_owned().await;
             if user_chat_permit.is_err() {
-                return Err("User chat semaphore error".into());
+                return Err(TelegrapherError::RateLimiter(
+                    "user chat semaphore error".to_string(),
+                ));
             }
 
             let global_chat_sem = rate_limiter.acquire_global().await;
             let global_chat_permit = global_chat_sem.acquire_owned().await;
             if global_chat_permit.is_err() {
-                return Err("Global chat semaphore error".into());
+                return Err(TelegrapherError::RateLimiter(
+                    "global chat semaphore error".to_string(),
+                ));
             }
 
             let self_clone = self.clone();
@@ -214,13 +311,17 @@ impl Bot {
             let group_chat_sem = rate_limiter.acquire_user_chat(chat_id).await;
             let group_chat_permit = group_chat_sem.acquire_owned().await;
             if group_chat_permit.is_err() {
-                return Err("Group chat semaphore error".into());
+                return Err(TelegrapherError::RateLimiter(
+                    "group chat semaphore error".to_string(),
+                ));
             }
 
             let global_chat_sem = rate_limiter.acquire_global().await.clone();
             let global_chat_permit = global_chat_sem.acquire_owned().await;
             if global_chat_permit.is_err() {
-                return Err("Global chat semaphore error".into());
+                return Err(TelegrapherError::RateLimiter(
+                    "global chat semaphore error".to_string(),
+                ));
             }
 
             let self_clone = self.clone();
@@ -275,12 +376,19 @@ impl Bot {
     }
 
     async fn process_update(&self, content: &UpdateContent) -> TelegrapherResult<Option<JsonData>> {
-        let handler;
-        {
-            handler = self.handler.lock().await;
+        // clone the handlers, so requests sent by them can use the handlers too.
+        let handler = self.handler.lock().await.clone();
+        if let Some(payment_flow) = &handler.payment_flow {
+            if let Some(result) = payment_flow.process_update(self, content).await {
+                return result;
+            }
         }
         match content {
             UpdateContent::Message(message) => {
+                if let Some(new_chat_id) = message.migrate_to_chat_id {
+                    self.notify_chat_migration(message.chat.id, new_chat_id)
+                        .await;
+                }
                 // if the content of message is a command
                 if let Some(text) = message.text.as_ref() {
                     if text.starts_with('/') {
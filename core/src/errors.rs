@@ -0,0 +1,320 @@
+use std::fmt::Display;
+
+use crate::responses::ResponseParameters;
+
+/// All errors returned by telegrapher.
+#[derive(Debug)]
+pub enum TelegrapherError {
+    /// The http request failed: connection, timeout, tls and so on.
+    Network(reqwest::Error),
+    /// The response body is not the json we expected, `body` keeps the raw response.
+    Json {
+        source: serde_json::Error,
+        body: String,
+    },
+    /// The params could not be encoded to json.
+    Encode(serde_json::Error),
+    /// Telegram answered the request with `ok: false`.
+    Api(ApiError),
+    /// Reading or writing a local file failed.
+    Io(std::io::Error),
+    /// A downloaded file is larger than the allowed size, `size` is the size seen so far.
+    FileTooLarge { size: u64, limit: u64 },
+    /// The rate limiter could not give a permit to send the message.
+    RateLimiter(String),
+    /// The params are not valid for the method, e.g. a file upload without a file name.
+    InvalidParams(String),
+    /// Any other error, mostly returned from user handlers.
+    Other(String),
+}
+
+impl Display for TelegrapherError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            TelegrapherError::Network(e) => write!(f, "network error: {}", e),
+            TelegrapherError::Json { source, body } => {
+                write!(f, "failed to decode response: {}, body: {}", source, body)
+            }
+            TelegrapherError::Encode(e) => write!(f, "failed to encode params: {}", e),
+            TelegrapherError::Api(e) => write!(f, "telegram api error: {}", e),
+            TelegrapherError::Io(e) => write!(f, "file io error: {}", e),
+            TelegrapherError::FileTooLarge { size, limit } => {
+                write!(
+                    f,
+                    "file of {} bytes exceeds the limit of {} bytes",
+                    size, limit
+                )
+            }
+            TelegrapherError::RateLimiter(message) => write!(f, "rate limiter error: {}", message),
+            TelegrapherError::InvalidParams(message) => write!(f, "invalid params: {}", message),
+            TelegrapherError::Other(message) => write!(f, "{}", message),
+        }
+    }
+}
+
+impl TelegrapherError {
+    /// The kind of the Bot API error, `None` if the error didn't come from telegram.
+    pub fn api_error_kind(&self) -> Option<ApiErrorKind> {
+        match self {
+            TelegrapherError::Api(e) => Some(e.kind()),
+            _ => None,
+        }
+    }
+}
+
+impl std::error::Error for TelegrapherError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            TelegrapherError::Network(e) => Some(e),
+            TelegrapherError::Json { source, .. } => Some(source),
+            TelegrapherError::Encode(e) => Some(e),
+            TelegrapherError::Io(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+impl From<reqwest::Error> for TelegrapherError {
+    fn from(error: reqwest::Error) -> Self {
+        TelegrapherError::Network(error)
+    }
+}
+
+impl From<std::io::Error> for TelegrapherError {
+    fn from(error: std::io::Error) -> Self {
+        TelegrapherError::Io(error)
+    }
+}
+
+impl From<ApiError> for TelegrapherError {
+    fn from(error: ApiError) -> Self {
+        TelegrapherError::Api(error)
+    }
+}
+
+impl From<&str> for TelegrapherError {
+    fn from(message: &str) -> Self {
+        TelegrapherError::Other(message.to_string())
+    }
+}
+
+impl From<String> for TelegrapherError {
+    fn from(message: String) -> Self {
+        TelegrapherError::Other(message)
+    }
+}
+
+/// Error returned by the Bot API, see [the official docs](https://core.telegram.org/bots/api#making-requests).
+#[derive(Debug, Clone, PartialEq)]
+pub struct ApiError {
+    pub error_code: u64,
+    pub description: String,
+    pub parameters: Option<ResponseParameters>,
+}
+
+impl ApiError {
+    /// Classify the error by its `error_code` and `description`.
+    pub fn kind(&self) -> ApiErrorKind {
+        if let Some(migrate_to_chat_id) = self.migrate_to_chat_id() {
+            return ApiErrorKind::GroupMigrated(migrate_to_chat_id);
+        }
+        if self.error_code == 429 {
+            return ApiErrorKind::FloodWait(self.retry_after().unwrap_or_default());
+        }
+        if self.error_code >= 500 {
+            return ApiErrorKind::ServerError;
+        }
+
+        let description = self.description.to_lowercase();
+        let contains = |pattern: &str| description.contains(pattern);
+        if contains("bot was blocked by the user") {
+            ApiErrorKind::BotBlocked
+        } else if contains("user is deactivated") {
+            ApiErrorKind::UserDeactivated
+        } else if contains("bot was kicked") || contains("bot is not a member") {
+            ApiErrorKind::BotKicked
+        } else if contains("bot can't initiate conversation") {
+            ApiErrorKind::CantInitiateConversation
+        } else if contains("chat not found") {
+            ApiErrorKind::ChatNotFound
+        } else if contains("user not found") {
+            ApiErrorKind::UserNotFound
+        } else if contains("message is not modified") {
+            ApiErrorKind::MessageNotModified
+        } else if contains("message to edit not found") {
+            ApiErrorKind::MessageToEditNotFound
+        } else if contains("message to delete not found") {
+            ApiErrorKind::MessageToDeleteNotFound
+        } else if contains("message can't be edited") {
+            ApiErrorKind::MessageCantBeEdited
+        } else if contains("message can't be deleted") {
+            ApiErrorKind::MessageCantBeDeleted
+        } else if contains("query is too old") {
+            ApiErrorKind::QueryTooOld
+        } else if contains("not enough rights")
+            || contains("have no rights")
+            || contains("chat_admin_required")
+        {
+            ApiErrorKind::NotEnoughRights
+        } else if contains("group chat was upgraded to a supergroup") {
+            ApiErrorKind::GroupMigrated(0)
+        } else {
+            ApiErrorKind::Unknown
+        }
+    }
+
+    /// Seconds to wait before the request can be repeated, when flood control is exceeded.
+    pub fn retry_after(&self) -> Option<u64> {
+        self.parameters.and_then(|p| p.retry_after)
+    }
+
+    /// The new identifier of the chat, when the group has been migrated to a supergroup.
+    pub fn migrate_to_chat_id(&self) -> Option<i64> {
+        self.parameters.and_then(|p| p.migrate_to_chat_id)
+    }
+}
+
+impl Display for ApiError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "[{}] {}", self.error_code, self.description)
+    }
+}
+
+/// Common Bot API failures, see `ApiError::kind`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ApiErrorKind {
+    /// Forbidden: bot was blocked by the user
+    BotBlocked,
+    /// Forbidden: user is deactivated
+    UserDeactivated,
+    /// Forbidden: bot was kicked from the chat, or is not a member of it
+    BotKicked,
+    /// Forbidden: bot can't initiate conversation with a user
+    CantInitiateConversation,
+    /// Bad Request: chat not found
+    ChatNotFound,
+    /// Bad Request: user not found
+    UserNotFound,
+    /// Bad Request: message is not modified
+    MessageNotModified,
+    /// Bad Request: message to edit not found
+    MessageToEditNotFound,
+    /// Bad Request: message to delete not found
+    MessageToDeleteNotFound,
+    /// Bad Request: message can't be edited
+    MessageCantBeEdited,
+    /// Bad Request: message can't be deleted
+    MessageCantBeDeleted,
+    /// Bad Request: query is too old and response timeout expired or query ID is invalid
+    QueryTooOld,
+    /// Bad Request: not enough rights, or the bot is not an administrator
+    NotEnoughRights,
+    /// Too Many Requests, with the seconds to wait before retrying
+    FloodWait(u64),
+    /// The group has been upgraded to a supergroup with this chat id, 0 if telegram didn't send it
+    GroupMigrated(i64),
+    /// Telegram had an internal error, the request may succeed later
+    ServerError,
+    /// Any error not listed above
+    Unknown,
+}
+
+impl ApiErrorKind {
+    /// The chat can't receive messages from the bot anymore, e.g. drop it from a subscriber list.
+    pub fn is_chat_unreachable(&self) -> bool {
+        matches!(
+            self,
+            ApiErrorKind::BotBlocked
+                | ApiErrorKind::UserDeactivated
+                | ApiErrorKind::BotKicked
+                | ApiErrorKind::CantInitiateConversation
+                | ApiErrorKind::ChatNotFound
+        )
+    }
+
+    /// The same request may succeed if it is sent again later.
+    pub fn is_retryable(&self) -> bool {
+        matches!(self, ApiErrorKind::FloodWait(_) | ApiErrorKind::ServerError)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn api_error(error_code: u64, description: &str) -> ApiError {
+        ApiError {
+            error_code,
+            description: description.to_string(),
+            parameters: None,
+        }
+    }
+
+    #[test]
+    fn test_api_error_kind() {
+        let cases = [
+            (403, "Forbidden: bot was blocked by the user", ApiErrorKind::BotBlocked),
+            (403, "Forbidden: user is deactivated", ApiErrorKind::UserDeactivated),
+            (
+                403,
+                "Forbidden: bot was kicked from the supergroup chat",
+                ApiErrorKind::BotKicked,
+            ),
+            (400, "Bad Request: chat not found", ApiErrorKind::ChatNotFound),
+            (
+                400,
+                "Bad Request: message is not modified: specified new message content and reply markup are exactly the same",
+                ApiErrorKind::MessageNotModified,
+            ),
+            (
+                400,
+                "Bad Request: message to edit not found",
+                ApiErrorKind::MessageToEditNotFound,
+            ),
+            (
+                400,
+                "Bad Request: query is too old and response timeout expired or query ID is invalid",
+                ApiErrorKind::QueryTooOld,
+            ),
+            (
+                400,
+                "Bad Request: not enough rights to send text messages to the chat",
+                ApiErrorKind::NotEnoughRights,
+            ),
+            (502, "Bad Gateway", ApiErrorKind::ServerError),
+            (400, "Bad Request: something new", ApiErrorKind::Unknown),
+        ];
+        for (error_code, description, kind) in cases {
+            assert_eq!(
+                api_error(error_code, description).kind(),
+                kind,
+                "{}",
+                description
+            );
+        }
+    }
+
+    #[test]
+    fn test_api_error_kind_from_parameters() {
+        let mut error = api_error(429, "Too Many Requests: retry after 7");
+        error.parameters = Some(ResponseParameters {
+            migrate_to_chat_id: None,
+            retry_after: Some(7),
+        });
+        assert_eq!(error.kind(), ApiErrorKind::FloodWait(7));
+        assert!(error.kind().is_retryable());
+
+        let mut error = api_error(
+            400,
+            "Bad Request: group chat was upgraded to a supergroup chat",
+        );
+        error.parameters = Some(ResponseParameters {
+            migrate_to_chat_id: Some(-1001234567890),
+            retry_after: None,
+        });
+        assert_eq!(error.kind(), ApiErrorKind::GroupMigrated(-1001234567890));
+        assert!(api_error(403, "Forbidden: bot was blocked by the user")
+            .kind()
+            .is_chat_unreachable());
+    }
+}
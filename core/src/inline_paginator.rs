@@ -0,0 +1,243 @@
+use std::future::Future;
+
+use crate::{
+    bot::Bot,
+    models::inline_query::{InlineQuery, InlineQueryResult, InlineQueryResultsButton},
+    params::inline_query_params::AnswerInlineQueryParams,
+    TelegrapherError, TelegrapherResult,
+};
+
+/// Most results telegram accepts in one answer.
+pub const MAX_INLINE_RESULTS: usize = 50;
+/// Longest result id and `next_offset` telegram accepts, in bytes.
+pub const MAX_INLINE_ID_LENGTH: usize = 64;
+
+/// The page of results asked by an inline query.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct InlinePageRequest {
+    /// Text of the query
+    pub query: String,
+    /// Index of the first result of the page
+    pub offset: usize,
+    /// Results to fetch: one more than the page size, the extra result tells if there is a next page
+    pub limit: usize,
+}
+
+/// Answers inline queries page by page, keeping the position in `InlineQuery::offset`.
+///
+/// ```no_run
+/// # use core::{bot::Bot, inline_paginator::InlineQueryPaginator, models::inline_query::InlineQuery};
+/// # async fn on_inline_query(bot: Bot, query: InlineQuery) -> core::TelegrapherResult<()> {
+/// InlineQueryPaginator::new(20)
+///     .answer(&bot, &query, |page| async move {
+///         // fetch `page.limit` items starting at `page.offset` matching `page.query`
+///         Ok(Vec::new())
+///     })
+///     .await?;
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct InlineQueryPaginator {
+    page_size: usize,
+    cache_time: Option<u64>,
+    is_personal: Option<bool>,
+    button: Option<InlineQueryResultsButton>,
+}
+
+impl InlineQueryPaginator {
+    /// `page_size` is clamped to 1-50.
+    pub fn new(page_size: usize) -> Self {
+        Self {
+            page_size: page_size.clamp(1, MAX_INLINE_RESULTS),
+            cache_time: None,
+            is_personal: None,
+            button: None,
+        }
+    }
+
+    pub fn with_cache_time(mut self, cache_time: u64) -> Self {
+        self.cache_time = Some(cache_time);
+        self
+    }
+
+    pub fn with_personal(mut self, is_personal: bool) -> Self {
+        self.is_personal = Some(is_personal);
+        self
+    }
+
+    pub fn with_button(mut self, button: InlineQueryResultsButton) -> Self {
+        self.button = Some(button);
+        self
+    }
+
+    pub fn page_size(&self) -> usize {
+        self.page_size
+    }
+
+    /// The index encoded in `InlineQuery::offset`, 0 for the first page or an offset we didn't send.
+    pub fn decode_offset(offset: &str) -> usize {
+        offset.parse().unwrap_or(0)
+    }
+
+    pub fn encode_offset(offset: usize) -> String {
+        offset.to_string()
+    }
+
+    /// The page the inline query asks for.
+    pub fn page_request(&self, query: &InlineQuery) -> InlinePageRequest {
+        InlinePageRequest {
+            query: query.query.clone(),
+            offset: Self::decode_offset(&query.offset),
+            limit: self.page_size + 1,
+        }
+    }
+
+    /// Build the answer of a page. Results past the page size are dropped and make the answer
+    /// get a `next_offset` pointing to the next page, which starts with the first dropped result.
+    pub fn build_answer(
+        &self,
+        query: &InlineQuery,
+        mut results: Vec<InlineQueryResult>,
+    ) -> TelegrapherResult<AnswerInlineQueryParams> {
+        let offset = Self::decode_offset(&query.offset);
+        let has_next_page = results.len() > self.page_size;
+        results.truncate(self.page_size);
+
+        let mut ids = std::collections::HashSet::new();
+        for result in &results {
+            let id = result.id();
+            if id.is_empty() || id.len() > MAX_INLINE_ID_LENGTH {
+                return Err(TelegrapherError::InvalidParams(format!(
+                    "inline query result id must be 1-{} bytes, got {:?}",
+                    MAX_INLINE_ID_LENGTH, id
+                )));
+            }
+            if !ids.insert(id) {
+                return Err(TelegrapherError::InvalidParams(format!(
+                    "duplicated inline query result id {:?}",
+                    id
+                )));
+            }
+        }
+
+        let next_offset = if has_next_page {
+            Self::encode_offset(offset + results.len())
+        } else {
+            String::new()
+        };
+        Ok(AnswerInlineQueryParams {
+            inline_query_id: query.id.clone(),
+            results,
+            cache_time: self.cache_time,
+            is_personal: self.is_personal,
+            next_offset: Some(next_offset),
+            button: self.button.clone(),
+        })
+    }
+
+    /// Fetch the page asked by `query` from `source` and answer the query with it.
+    pub async fn answer<F, Fut>(
+        &self,
+        bot: &Bot,
+        query: &InlineQuery,
+        source: F,
+    ) -> TelegrapherResult<bool>
+    where
+        F: FnOnce(InlinePageRequest) -> Fut,
+        Fut: Future<Output = TelegrapherResult<Vec<InlineQueryResult>>>,
+    {
+        let results = source(self.page_request(query)).await?;
+        let params = self.build_answer(query, results)?;
+        bot.answer_inline_query(&params).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::models::inline_query::InlineQueryResultGame;
+
+    use super::*;
+
+    fn inline_query(offset: &str) -> InlineQuery {
+        serde_json::from_value(serde_json::json!({
+            "id": "q1",
+            "from": {"id": 1, "is_bot": false, "first_name": "a"},
+            "query": "rust",
+            "offset": offset,
+        }))
+        .unwrap()
+    }
+
+    fn results(range: std::ops::Range<usize>) -> Vec<InlineQueryResult> {
+        range
+            .map(|i| {
+                InlineQueryResultGame {
+                    id: i.to_string(),
+                    game_short_name: "game".to_string(),
+                    reply_markup: None,
+                }
+                .into()
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_pages() {
+        let paginator = InlineQueryPaginator::new(100);
+        assert_eq!(paginator.page_size(), MAX_INLINE_RESULTS);
+
+        let paginator = InlineQueryPaginator::new(10);
+        let query = inline_query("");
+        assert_eq!(paginator.page_request(&query).offset, 0);
+        let answer = paginator.build_answer(&query, results(0..11)).unwrap();
+        assert_eq!(answer.results.len(), 10);
+        assert_eq!(answer.next_offset.as_deref(), Some("10"));
+
+        let query = inline_query("10");
+        let request = paginator.page_request(&query);
+        assert_eq!((request.offset, request.limit), (10, 11));
+        let answer = paginator.build_answer(&query, results(10..13)).unwrap();
+        assert_eq!(answer.results.len(), 3);
+        assert_eq!(answer.next_offset.as_deref(), Some(""));
+    }
+
+    #[test]
+    fn test_exactly_full_last_page() {
+        let paginator = InlineQueryPaginator::new(10);
+        let answer = paginator
+            .build_answer(&inline_query("10"), results(10..20))
+            .unwrap();
+        assert_eq!(answer.results.len(), 10);
+        assert_eq!(answer.next_offset.as_deref(), Some(""));
+    }
+
+    #[test]
+    fn test_oversized_page() {
+        let paginator = InlineQueryPaginator::new(10);
+        let answer = paginator
+            .build_answer(&inline_query(""), results(0..25))
+            .unwrap();
+        assert_eq!(answer.results.len(), 10);
+        // the next page starts right after the last shown result, nothing is skipped
+        assert_eq!(answer.next_offset.as_deref(), Some("10"));
+        let request = paginator.page_request(&inline_query("10"));
+        assert_eq!(request.offset, 10);
+    }
+
+    #[test]
+    fn test_invalid_result_ids() {
+        let paginator = InlineQueryPaginator::new(10);
+        let mut duplicated = results(0..2);
+        duplicated.extend(results(1..2));
+        assert!(paginator
+            .build_answer(&inline_query(""), duplicated)
+            .is_err());
+
+        let mut too_long = results(0..1);
+        if let InlineQueryResult::Game(game) = &mut too_long[0] {
+            game.id = "x".repeat(65);
+        }
+        assert!(paginator.build_answer(&inline_query(""), too_long).is_err());
+    }
+}
@@ -1,5 +1,4 @@
 use std::collections::HashMap;
-use std::fmt::Display;
 use std::future::Future;
 use std::pin::Pin;
 use std::sync::Arc;
@@ -8,17 +7,25 @@ use tokio::sync::{Mutex, Semaphore};
 
 use bot::Bot;
 use models::{message::Message, update::UpdateContent};
+use payments::PaymentFlow;
 
 pub mod bot;
+pub mod errors;
+pub mod inline_paginator;
 pub mod methods;
+pub mod middleware;
 pub mod models;
 pub mod params;
+pub mod payments;
 pub mod requests;
 pub mod responses;
+#[cfg(test)]
+mod test_server;
 
 pub const TELEGRAM_API_URL: &str = "https://api.telegram.org";
 
-pub type TelegrapherError = Box<dyn std::error::Error + Send + Sync>;
+pub use errors::{ApiError, ApiErrorKind, TelegrapherError};
+
 pub type TelegrapherResult<T> = Result<T, TelegrapherError>;
 pub type JsonData = serde_json::Value;
 
@@ -43,11 +50,18 @@ pub type CommandHandler =
         String,
     ) -> Pin<Box<dyn Future<Output = TelegrapherResult<Option<JsonData>>> + Send>>;
 
+/// Chat migration handler type, called with the old and the new chat id
+/// when a group has been migrated to a supergroup.
+pub type ChatMigrationHandler =
+    fn(Bot, i64, i64) -> Pin<Box<dyn Future<Output = TelegrapherResult<Option<JsonData>>> + Send>>;
+
 #[derive(Debug, Default, Clone)]
 pub struct EventHandler {
     pub commands: Vec<String>,
     pub update_handler: Option<UpdateHandler>,
     pub command_handler: Option<CommandHandler>,
+    pub chat_migration_handler: Option<ChatMigrationHandler>,
+    pub payment_flow: Option<Arc<PaymentFlow>>,
 }
 
 impl EventHandler {
@@ -61,6 +75,14 @@ impl EventHandler {
             self.commands.push(cmd.as_str().to_string());
         }
     }
+
+    pub fn register_chat_migration_handler(&mut self, handler: ChatMigrationHandler) {
+        self.chat_migration_handler = Some(handler);
+    }
+
+    pub fn register_payment_flow(&mut self, payment_flow: PaymentFlow) {
+        self.payment_flow = Some(Arc::new(payment_flow));
+    }
 }
 
 #[derive(Debug)]
@@ -129,30 +151,6 @@ impl RateLimitSemaphore {
     }
 }
 
-#[derive(Debug, Clone)]
-pub enum FileType {
-    Photo,
-    Video,
-    Document,
-    Audio,
-    Voice,
-    Animation,
-}
-
-impl Display for FileType {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        let str = match self {
-            FileType::Photo => "photo".to_string(),
-            FileType::Video => "video".to_string(),
-            FileType::Document => "document".to_string(),
-            FileType::Audio => "audio".to_string(),
-            FileType::Voice => "voice".to_string(),
-            FileType::Animation => "animation".to_string(),
-        };
-        write!(f, "{}", str)
-    }
-}
-
 #[cfg(test)]
 mod tests {
     use std::time::Duration;
@@ -164,7 +162,7 @@ mod tests {
         let rate_limiter = Arc::new(RateLimitSemaphore::default());
         let mut tasks = Vec::new();
         for i in 0..10 {
-            let rate_limiter = rate_limiter.clone();
+            let _rate_limiter = rate_limiter.clone();
             let task = tokio::spawn(async move {
                 println!("Task {} ", i);
                 // let sem = rate_limiter.acquire_user_chat(1).await;
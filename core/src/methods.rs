@@ -1,6 +1,12 @@
+pub mod chat;
 pub mod command;
+pub mod file;
+pub mod forum;
+pub mod inline_query;
 pub mod media;
 pub mod message;
+pub mod payment;
+pub mod sticker;
 pub mod updates;
 pub mod user;
 pub mod webhook;
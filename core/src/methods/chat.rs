@@ -0,0 +1,318 @@
+use crate::{
+    bot::Bot,
+    models::{
+        chat::{ChatFullInfo, ChatInviteLink},
+        chat_member::ChatMember,
+    },
+    params::chat_params::{
+        BanChatMemberParams, BanChatSenderChatParams, ChatIdParams, ChatJoinRequestParams,
+        CreateChatInviteLinkParams, CreateChatSubscriptionInviteLinkParams,
+        EditChatInviteLinkParams, EditChatSubscriptionInviteLinkParams, GetChatMemberParams,
+        PromoteChatMemberParams, RestrictChatMemberParams, RevokeChatInviteLinkParams,
+        SetChatAdministratorCustomTitleParams, SetChatDescriptionParams, SetChatPermissionsParams,
+        SetChatPhotoParams, SetChatTitleParams, UnbanChatMemberParams, UnbanChatSenderChatParams,
+    },
+    requests, TelegrapherError,
+};
+
+impl Bot {
+    /// Ban a user from a group, supergroup or channel. The bot must be an administrator with the rights to restrict members.
+    /// [The official docs](https://core.telegram.org/bots/api#banchatmember)
+    pub async fn ban_chat_member(
+        &self,
+        params: &BanChatMemberParams,
+    ) -> Result<bool, TelegrapherError> {
+        requests::post_request::<BanChatMemberParams, bool>(self, "banChatMember", Some(params))
+            .await
+    }
+
+    /// Unban a previously banned user, the user is not added back to the chat.
+    /// [The official docs](https://core.telegram.org/bots/api#unbanchatmember)
+    pub async fn unban_chat_member(
+        &self,
+        params: &UnbanChatMemberParams,
+    ) -> Result<bool, TelegrapherError> {
+        requests::post_request::<UnbanChatMemberParams, bool>(self, "unbanChatMember", Some(params))
+            .await
+    }
+
+    /// Restrict a user in a supergroup, pass all permissions as true to lift the restrictions.
+    /// [The official docs](https://core.telegram.org/bots/api#restrictchatmember)
+    pub async fn restrict_chat_member(
+        &self,
+        params: &RestrictChatMemberParams,
+    ) -> Result<bool, TelegrapherError> {
+        requests::post_request::<RestrictChatMemberParams, bool>(
+            self,
+            "restrictChatMember",
+            Some(params),
+        )
+        .await
+    }
+
+    /// Promote or demote a user, pass all rights as false to demote.
+    /// [The official docs](https://core.telegram.org/bots/api#promotechatmember)
+    pub async fn promote_chat_member(
+        &self,
+        params: &PromoteChatMemberParams,
+    ) -> Result<bool, TelegrapherError> {
+        requests::post_request::<PromoteChatMemberParams, bool>(
+            self,
+            "promoteChatMember",
+            Some(params),
+        )
+        .await
+    }
+
+    /// Set a custom title for an administrator promoted by the bot.
+    /// [The official docs](https://core.telegram.org/bots/api#setchatadministratorcustomtitle)
+    pub async fn set_chat_administrator_custom_title(
+        &self,
+        params: &SetChatAdministratorCustomTitleParams,
+    ) -> Result<bool, TelegrapherError> {
+        requests::post_request::<SetChatAdministratorCustomTitleParams, bool>(
+            self,
+            "setChatAdministratorCustomTitle",
+            Some(params),
+        )
+        .await
+    }
+
+    /// Ban a channel chat, its owner can't send messages on behalf of any of their channels.
+    /// [The official docs](https://core.telegram.org/bots/api#banchatsenderchat)
+    pub async fn ban_chat_sender_chat(
+        &self,
+        params: &BanChatSenderChatParams,
+    ) -> Result<bool, TelegrapherError> {
+        requests::post_request::<BanChatSenderChatParams, bool>(
+            self,
+            "banChatSenderChat",
+            Some(params),
+        )
+        .await
+    }
+
+    /// Unban a previously banned channel chat.
+    /// [The official docs](https://core.telegram.org/bots/api#unbanchatsenderchat)
+    pub async fn unban_chat_sender_chat(
+        &self,
+        params: &UnbanChatSenderChatParams,
+    ) -> Result<bool, TelegrapherError> {
+        requests::post_request::<UnbanChatSenderChatParams, bool>(
+            self,
+            "unbanChatSenderChat",
+            Some(params),
+        )
+        .await
+    }
+
+    /// Set the default permissions of all members.
+    /// [The official docs](https://core.telegram.org/bots/api#setchatpermissions)
+    pub async fn set_chat_permissions(
+        &self,
+        params: &SetChatPermissionsParams,
+    ) -> Result<bool, TelegrapherError> {
+        requests::post_request::<SetChatPermissionsParams, bool>(
+            self,
+            "setChatPermissions",
+            Some(params),
+        )
+        .await
+    }
+
+    /// Get information about a member of a chat.
+    /// [The official docs](https://core.telegram.org/bots/api#getchatmember)
+    pub async fn get_chat_member(
+        &self,
+        params: &GetChatMemberParams,
+    ) -> Result<ChatMember, TelegrapherError> {
+        requests::post_request::<GetChatMemberParams, ChatMember>(
+            self,
+            "getChatMember",
+            Some(params),
+        )
+        .await
+    }
+
+    /// Get the administrators of a chat, other bots are not included.
+    /// [The official docs](https://core.telegram.org/bots/api#getchatadministrators)
+    pub async fn get_chat_administrators(
+        &self,
+        params: &ChatIdParams,
+    ) -> Result<Vec<ChatMember>, TelegrapherError> {
+        requests::post_request::<ChatIdParams, Vec<ChatMember>>(
+            self,
+            "getChatAdministrators",
+            Some(params),
+        )
+        .await
+    }
+
+    /// Get the number of members in a chat.
+    /// [The official docs](https://core.telegram.org/bots/api#getchatmembercount)
+    pub async fn get_chat_member_count(
+        &self,
+        params: &ChatIdParams,
+    ) -> Result<u64, TelegrapherError> {
+        requests::post_request::<ChatIdParams, u64>(self, "getChatMemberCount", Some(params)).await
+    }
+
+    /// Generate a new primary invite link, the previous primary link is revoked.
+    /// [The official docs](https://core.telegram.org/bots/api#exportchatinvitelink)
+    pub async fn export_chat_invite_link(
+        &self,
+        params: &ChatIdParams,
+    ) -> Result<String, TelegrapherError> {
+        requests::post_request::<ChatIdParams, String>(self, "exportChatInviteLink", Some(params))
+            .await
+    }
+
+    /// Create an additional invite link, it can be revoked with `revoke_chat_invite_link`.
+    /// [The official docs](https://core.telegram.org/bots/api#createchatinvitelink)
+    pub async fn create_chat_invite_link(
+        &self,
+        params: &CreateChatInviteLinkParams,
+    ) -> Result<ChatInviteLink, TelegrapherError> {
+        requests::post_request::<CreateChatInviteLinkParams, ChatInviteLink>(
+            self,
+            "createChatInviteLink",
+            Some(params),
+        )
+        .await
+    }
+
+    /// Edit a non-primary invite link created by the bot.
+    /// [The official docs](https://core.telegram.org/bots/api#editchatinvitelink)
+    pub async fn edit_chat_invite_link(
+        &self,
+        params: &EditChatInviteLinkParams,
+    ) -> Result<ChatInviteLink, TelegrapherError> {
+        requests::post_request::<EditChatInviteLinkParams, ChatInviteLink>(
+            self,
+            "editChatInviteLink",
+            Some(params),
+        )
+        .await
+    }
+
+    /// Create a subscription invite link for a channel chat.
+    /// [The official docs](https://core.telegram.org/bots/api#createchatsubscriptioninvitelink)
+    pub async fn create_chat_subscription_invite_link(
+        &self,
+        params: &CreateChatSubscriptionInviteLinkParams,
+    ) -> Result<ChatInviteLink, TelegrapherError> {
+        requests::post_request::<CreateChatSubscriptionInviteLinkParams, ChatInviteLink>(
+            self,
+            "createChatSubscriptionInviteLink",
+            Some(params),
+        )
+        .await
+    }
+
+    /// Edit the name of a subscription invite link created by the bot.
+    /// [The official docs](https://core.telegram.org/bots/api#editchatsubscriptioninvitelink)
+    pub async fn edit_chat_subscription_invite_link(
+        &self,
+        params: &EditChatSubscriptionInviteLinkParams,
+    ) -> Result<ChatInviteLink, TelegrapherError> {
+        requests::post_request::<EditChatSubscriptionInviteLinkParams, ChatInviteLink>(
+            self,
+            "editChatSubscriptionInviteLink",
+            Some(params),
+        )
+        .await
+    }
+
+    /// Revoke an invite link created by the bot, a revoked primary link is replaced with a new one.
+    /// [The official docs](https://core.telegram.org/bots/api#revokechatinvitelink)
+    pub async fn revoke_chat_invite_link(
+        &self,
+        params: &RevokeChatInviteLinkParams,
+    ) -> Result<ChatInviteLink, TelegrapherError> {
+        requests::post_request::<RevokeChatInviteLinkParams, ChatInviteLink>(
+            self,
+            "revokeChatInviteLink",
+            Some(params),
+        )
+        .await
+    }
+
+    /// Approve a chat join request.
+    /// [The official docs](https://core.telegram.org/bots/api#approvechatjoinrequest)
+    pub async fn approve_chat_join_request(
+        &self,
+        params: &ChatJoinRequestParams,
+    ) -> Result<bool, TelegrapherError> {
+        requests::post_request::<ChatJoinRequestParams, bool>(
+            self,
+            "approveChatJoinRequest",
+            Some(params),
+        )
+        .await
+    }
+
+    /// Decline a chat join request.
+    /// [The official docs](https://core.telegram.org/bots/api#declinechatjoinrequest)
+    pub async fn decline_chat_join_request(
+        &self,
+        params: &ChatJoinRequestParams,
+    ) -> Result<bool, TelegrapherError> {
+        requests::post_request::<ChatJoinRequestParams, bool>(
+            self,
+            "declineChatJoinRequest",
+            Some(params),
+        )
+        .await
+    }
+
+    /// Get up to date information about a chat.
+    /// [The official docs](https://core.telegram.org/bots/api#getchat)
+    pub async fn get_chat(&self, params: &ChatIdParams) -> Result<ChatFullInfo, TelegrapherError> {
+        requests::post_request::<ChatIdParams, ChatFullInfo>(self, "getChat", Some(params)).await
+    }
+
+    /// Change the title of a chat, titles of private chats can't be changed.
+    /// [The official docs](https://core.telegram.org/bots/api#setchattitle)
+    pub async fn set_chat_title(
+        &self,
+        params: &SetChatTitleParams,
+    ) -> Result<bool, TelegrapherError> {
+        requests::post_request::<SetChatTitleParams, bool>(self, "setChatTitle", Some(params)).await
+    }
+
+    /// Change the description of a group, supergroup or channel.
+    /// [The official docs](https://core.telegram.org/bots/api#setchatdescription)
+    pub async fn set_chat_description(
+        &self,
+        params: &SetChatDescriptionParams,
+    ) -> Result<bool, TelegrapherError> {
+        requests::post_request::<SetChatDescriptionParams, bool>(
+            self,
+            "setChatDescription",
+            Some(params),
+        )
+        .await
+    }
+
+    /// Upload a new profile photo for a chat, photos of private chats can't be changed.
+    /// [The official docs](https://core.telegram.org/bots/api#setchatphoto)
+    pub async fn set_chat_photo(
+        &self,
+        params: &SetChatPhotoParams,
+    ) -> Result<bool, TelegrapherError> {
+        requests::post_multi_part_request::<SetChatPhotoParams, bool>(self, "setChatPhoto", params)
+            .await
+    }
+
+    /// Delete the profile photo of a chat.
+    /// [The official docs](https://core.telegram.org/bots/api#deletechatphoto)
+    pub async fn delete_chat_photo(&self, params: &ChatIdParams) -> Result<bool, TelegrapherError> {
+        requests::post_request::<ChatIdParams, bool>(self, "deleteChatPhoto", Some(params)).await
+    }
+
+    /// Leave a group, supergroup or channel.
+    /// [The official docs](https://core.telegram.org/bots/api#leavechat)
+    pub async fn leave_chat(&self, params: &ChatIdParams) -> Result<bool, TelegrapherError> {
+        requests::post_request::<ChatIdParams, bool>(self, "leaveChat", Some(params)).await
+    }
+}
@@ -2,31 +2,25 @@ use crate::{
     bot::Bot,
     models::command::BotCommand,
     params::command_params::{DeleteMyCommandsParams, GetMyCommandsParams, SetMyCommandsParams},
-    requests,
-    responses::MethodResponse,
-    TelegrapherError,
+    requests, TelegrapherError,
 };
 
 impl Bot {
     pub async fn set_my_commands(
         &self,
         params: &SetMyCommandsParams,
-    ) -> Result<MethodResponse<bool>, TelegrapherError> {
-        requests::post_request::<SetMyCommandsParams, bool>(
-            "setMyCommands",
-            self.token(),
-            Some(params),
-        )
-        .await
+    ) -> Result<bool, TelegrapherError> {
+        requests::post_request::<SetMyCommandsParams, bool>(self, "setMyCommands", Some(params))
+            .await
     }
 
     pub async fn delete_my_commands(
         &self,
         params: &DeleteMyCommandsParams,
-    ) -> Result<MethodResponse<bool>, TelegrapherError> {
+    ) -> Result<bool, TelegrapherError> {
         requests::post_request::<DeleteMyCommandsParams, bool>(
+            self,
             "deleteMyCommands",
-            self.token(),
             Some(params),
         )
         .await
@@ -35,10 +29,10 @@ impl Bot {
     pub async fn get_my_commands(
         &self,
         params: &GetMyCommandsParams,
-    ) -> Result<MethodResponse<Vec<BotCommand>>, TelegrapherError> {
+    ) -> Result<Vec<BotCommand>, TelegrapherError> {
         requests::post_request::<GetMyCommandsParams, Vec<BotCommand>>(
+            self,
             "getMyCommands",
-            self.token(),
             Some(params),
         )
         .await
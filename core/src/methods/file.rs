@@ -0,0 +1,200 @@
+use std::path::Path;
+
+use bytes::Bytes;
+use futures::{Stream, StreamExt};
+use tokio::io::{AsyncWrite, AsyncWriteExt};
+use tokio_util::io::ReaderStream;
+
+use crate::{
+    bot::Bot,
+    models::file::File,
+    params::file_params::{DownloadOptions, GetFileParams},
+    requests,
+    responses::MethodResponse,
+    ApiError, JsonData, TelegrapherError,
+};
+
+impl Bot {
+    /// Get the `file_path` of a file to download it, the path is valid for at least one hour.
+    /// [The official docs](https://core.telegram.org/bots/api#getfile)
+    pub async fn get_file(&self, params: &GetFileParams) -> Result<File, TelegrapherError> {
+        requests::post_request::<GetFileParams, File>(self, "getFile", Some(params)).await
+    }
+
+    /// Stream the file at `file_path` into `writer`, returns the number of bytes written.
+    /// The download is limited by `DownloadOptions::timeout` instead of the timeout of the bot's http client.
+    /// With `Bot::with_local_api_server`, an absolute `file_path` is read from the local filesystem,
+    /// otherwise every path is downloaded from `Bot::file_url`.
+    pub async fn download_file<W>(
+        &self,
+        file_path: &str,
+        writer: &mut W,
+        options: &DownloadOptions,
+    ) -> Result<u64, TelegrapherError>
+    where
+        W: AsyncWrite + Unpin + ?Sized,
+    {
+        if self.local_api_server && Path::new(file_path).is_absolute() {
+            let file = tokio::fs::File::open(file_path).await?;
+            let length = file.metadata().await?.len();
+            options.check_size(length)?;
+            return copy_stream(ReaderStream::new(file), Some(length), writer, options).await;
+        }
+
+        let response = self
+            .client
+            .get(self.file_url(file_path.trim_start_matches('/')))
+            .timeout(options.timeout())
+            .send()
+            .await?;
+        let status = response.status();
+        if !status.is_success() {
+            let body = response.text().await?;
+            let error = serde_json::from_str::<MethodResponse<JsonData>>(&body)
+                .ok()
+                .and_then(|response| response.api_error())
+                .unwrap_or(ApiError {
+                    error_code: status.as_u16() as u64,
+                    description: body,
+                    parameters: None,
+                });
+            return Err(TelegrapherError::Api(error));
+        }
+        let length = response.content_length();
+        if let Some(length) = length {
+            options.check_size(length)?;
+        }
+        copy_stream(response.bytes_stream(), length, writer, options).await
+    }
+
+    /// Download the file at `file_path` to a local path. A partially written file is removed on failure.
+    pub async fn download_file_to_path(
+        &self,
+        file_path: &str,
+        path: impl AsRef<Path>,
+        options: &DownloadOptions,
+    ) -> Result<u64, TelegrapherError> {
+        let path = path.as_ref();
+        let mut file = tokio::fs::File::create(path).await?;
+        let result = self.download_file(file_path, &mut file, options).await;
+        if result.is_err() {
+            drop(file);
+            _ = tokio::fs::remove_file(path).await;
+        }
+        result
+    }
+
+    /// Download the file at `file_path` into memory.
+    pub async fn download_file_to_memory(
+        &self,
+        file_path: &str,
+        options: &DownloadOptions,
+    ) -> Result<Bytes, TelegrapherError> {
+        let mut data = Vec::new();
+        self.download_file(file_path, &mut data, options).await?;
+        Ok(Bytes::from(data))
+    }
+}
+
+/// Write the chunks of `stream` into `writer`, enforcing the size limit and reporting the progress.
+async fn copy_stream<S, E, W>(
+    mut stream: S,
+    length: Option<u64>,
+    writer: &mut W,
+    options: &DownloadOptions,
+) -> Result<u64, TelegrapherError>
+where
+    S: Stream<Item = Result<Bytes, E>> + Unpin,
+    TelegrapherError: From<E>,
+    W: AsyncWrite + Unpin + ?Sized,
+{
+    let mut written = 0u64;
+    while let Some(chunk) = stream.next().await {
+        let chunk = chunk?;
+        written += chunk.len() as u64;
+        options.check_size(written)?;
+        writer.write_all(&chunk).await?;
+        if let Some(progress) = &options.progress {
+            progress(written, length);
+        }
+    }
+    writer.flush().await?;
+    Ok(written)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::{
+        atomic::{AtomicU64, Ordering},
+        Arc,
+    };
+
+    use axum::{extract::Path, routing::get, Router};
+
+    use super::*;
+
+    /// Serves every file path with its own name as the content.
+    async fn start_file_server() -> String {
+        let app = Router::new().route(
+            "/file/:token/*file_path",
+            get(|Path((_, file_path)): Path<(String, String)>| async move { file_path }),
+        );
+        crate::test_server::serve(app).await
+    }
+
+    #[tokio::test]
+    async fn test_download_file() {
+        let bot = Bot::new("123:abc", 1).with_api_url(&start_file_server().await);
+
+        let downloaded = Arc::new(AtomicU64::new(0));
+        let progress = downloaded.clone();
+        let options = DownloadOptions::default()
+            .with_progress(move |written, _| progress.store(written, Ordering::SeqCst));
+        let data = bot
+            .download_file_to_memory("documents/file_1.txt", &options)
+            .await
+            .unwrap();
+        assert_eq!(&data[..], b"documents/file_1.txt");
+        assert_eq!(downloaded.load(Ordering::SeqCst), 20);
+
+        let options = DownloadOptions::default().with_max_size(8);
+        let result = bot
+            .download_file_to_memory("documents/file_1.txt", &options)
+            .await;
+        assert!(matches!(
+            result,
+            Err(TelegrapherError::FileTooLarge { limit: 8, .. })
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_absolute_path_is_downloaded_without_local_api_server() {
+        let bot = Bot::new("123:abc", 1).with_api_url(&start_file_server().await);
+        let data = bot
+            .download_file_to_memory("/etc/hostname", &DownloadOptions::default())
+            .await
+            .unwrap();
+        assert_eq!(&data[..], b"etc/hostname");
+    }
+
+    #[tokio::test]
+    async fn test_download_local_file() {
+        let bot = Bot::new("123:abc", 1).with_local_api_server(true);
+        let local_path = std::env::temp_dir().join("telegrapher_test_download_local_file.txt");
+        tokio::fs::write(&local_path, b"voice note").await.unwrap();
+
+        let target_path = std::env::temp_dir().join("telegrapher_test_download_local_copy.txt");
+        let written = bot
+            .download_file_to_path(
+                local_path.to_str().unwrap(),
+                &target_path,
+                &DownloadOptions::default(),
+            )
+            .await
+            .unwrap();
+        assert_eq!(written, 10);
+        assert_eq!(tokio::fs::read(&target_path).await.unwrap(), b"voice note");
+        _ = tokio::fs::remove_file(local_path).await;
+        _ = tokio::fs::remove_file(target_path).await;
+    }
+}
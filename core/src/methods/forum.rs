@@ -0,0 +1,202 @@
+use crate::{
+    bot::Bot,
+    models::{forum::ForumTopic, sticker::Sticker},
+    params::{
+        chat_params::ChatIdParams,
+        forum_params::{
+            CreateForumTopicParams, EditForumTopicParams, EditGeneralForumTopicParams,
+            ForumTopicParams,
+        },
+    },
+    requests, TelegrapherError,
+};
+
+impl Bot {
+    /// Create a topic in a forum supergroup, the bot needs the can_manage_topics right.
+    /// [The official docs](https://core.telegram.org/bots/api#createforumtopic)
+    pub async fn create_forum_topic(
+        &self,
+        params: &CreateForumTopicParams,
+    ) -> Result<ForumTopic, TelegrapherError> {
+        requests::post_request::<CreateForumTopicParams, ForumTopic>(
+            self,
+            "createForumTopic",
+            Some(params),
+        )
+        .await
+    }
+
+    /// Edit the name and icon of a topic.
+    /// [The official docs](https://core.telegram.org/bots/api#editforumtopic)
+    pub async fn edit_forum_topic(
+        &self,
+        params: &EditForumTopicParams,
+    ) -> Result<bool, TelegrapherError> {
+        requests::post_request::<EditForumTopicParams, bool>(self, "editForumTopic", Some(params))
+            .await
+    }
+
+    /// Close an open topic.
+    /// [The official docs](https://core.telegram.org/bots/api#closeforumtopic)
+    pub async fn close_forum_topic(
+        &self,
+        params: &ForumTopicParams,
+    ) -> Result<bool, TelegrapherError> {
+        requests::post_request::<ForumTopicParams, bool>(self, "closeForumTopic", Some(params))
+            .await
+    }
+
+    /// Reopen a closed topic.
+    /// [The official docs](https://core.telegram.org/bots/api#reopenforumtopic)
+    pub async fn reopen_forum_topic(
+        &self,
+        params: &ForumTopicParams,
+    ) -> Result<bool, TelegrapherError> {
+        requests::post_request::<ForumTopicParams, bool>(self, "reopenForumTopic", Some(params))
+            .await
+    }
+
+    /// Delete a topic along with all its messages.
+    /// [The official docs](https://core.telegram.org/bots/api#deleteforumtopic)
+    pub async fn delete_forum_topic(
+        &self,
+        params: &ForumTopicParams,
+    ) -> Result<bool, TelegrapherError> {
+        requests::post_request::<ForumTopicParams, bool>(self, "deleteForumTopic", Some(params))
+            .await
+    }
+
+    /// Clear the list of pinned messages of a topic.
+    /// [The official docs](https://core.telegram.org/bots/api#unpinallforumtopicmessages)
+    pub async fn unpin_all_forum_topic_messages(
+        &self,
+        params: &ForumTopicParams,
+    ) -> Result<bool, TelegrapherError> {
+        requests::post_request::<ForumTopicParams, bool>(
+            self,
+            "unpinAllForumTopicMessages",
+            Some(params),
+        )
+        .await
+    }
+
+    /// Edit the name of the General topic.
+    /// [The official docs](https://core.telegram.org/bots/api#editgeneralforumtopic)
+    pub async fn edit_general_forum_topic(
+        &self,
+        params: &EditGeneralForumTopicParams,
+    ) -> Result<bool, TelegrapherError> {
+        requests::post_request::<EditGeneralForumTopicParams, bool>(
+            self,
+            "editGeneralForumTopic",
+            Some(params),
+        )
+        .await
+    }
+
+    /// Close the General topic.
+    /// [The official docs](https://core.telegram.org/bots/api#closegeneralforumtopic)
+    pub async fn close_general_forum_topic(
+        &self,
+        params: &ChatIdParams,
+    ) -> Result<bool, TelegrapherError> {
+        requests::post_request::<ChatIdParams, bool>(self, "closeGeneralForumTopic", Some(params))
+            .await
+    }
+
+    /// Reopen the General topic, it is unhidden if it was hidden.
+    /// [The official docs](https://core.telegram.org/bots/api#reopengeneralforumtopic)
+    pub async fn reopen_general_forum_topic(
+        &self,
+        params: &ChatIdParams,
+    ) -> Result<bool, TelegrapherError> {
+        requests::post_request::<ChatIdParams, bool>(self, "reopenGeneralForumTopic", Some(params))
+            .await
+    }
+
+    /// Hide the General topic, it is closed if it was open.
+    /// [The official docs](https://core.telegram.org/bots/api#hidegeneralforumtopic)
+    pub async fn hide_general_forum_topic(
+        &self,
+        params: &ChatIdParams,
+    ) -> Result<bool, TelegrapherError> {
+        requests::post_request::<ChatIdParams, bool>(self, "hideGeneralForumTopic", Some(params))
+            .await
+    }
+
+    /// Unhide the General topic.
+    /// [The official docs](https://core.telegram.org/bots/api#unhidegeneralforumtopic)
+    pub async fn unhide_general_forum_topic(
+        &self,
+        params: &ChatIdParams,
+    ) -> Result<bool, TelegrapherError> {
+        requests::post_request::<ChatIdParams, bool>(self, "unhideGeneralForumTopic", Some(params))
+            .await
+    }
+
+    /// Clear the list of pinned messages of the General topic.
+    /// [The official docs](https://core.telegram.org/bots/api#unpinallgeneralforumtopicmessages)
+    pub async fn unpin_all_general_forum_topic_messages(
+        &self,
+        params: &ChatIdParams,
+    ) -> Result<bool, TelegrapherError> {
+        requests::post_request::<ChatIdParams, bool>(
+            self,
+            "unpinAllGeneralForumTopicMessages",
+            Some(params),
+        )
+        .await
+    }
+
+    /// Get the custom emoji stickers any user can use as a forum topic icon.
+    /// [The official docs](https://core.telegram.org/bots/api#getforumtopiciconstickers)
+    pub async fn get_forum_topic_icon_stickers(&self) -> Result<Vec<Sticker>, TelegrapherError> {
+        requests::post_request::<(), Vec<Sticker>>(self, "getForumTopicIconStickers", None).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use axum::{routing::post, Json, Router};
+    use serde_json::{json, Value};
+
+    use crate::params::forum_params::{CreateForumTopicParamsBuilder, FORUM_TOPIC_ICON_COLORS};
+
+    use super::*;
+
+    #[tokio::test]
+    async fn test_create_forum_topic() {
+        // creates the topic in thread 42 with the requested name and color
+        let app = Router::new().route(
+            "/:token/:method",
+            post(|Json(params): Json<Value>| async move {
+                Json(json!({
+                    "ok": true,
+                    "result": {
+                        "message_thread_id": 42,
+                        "name": params["name"],
+                        "icon_color": params["icon_color"],
+                    }
+                }))
+            }),
+        );
+        let bot = Bot::new("123:abc", 1).with_api_url(&crate::test_server::serve(app).await);
+
+        let params = CreateForumTopicParamsBuilder::default()
+            .chat_id(-1001234)
+            .name("Releases")
+            .icon_color(FORUM_TOPIC_ICON_COLORS[0])
+            .build()
+            .unwrap();
+        let topic = bot.create_forum_topic(&params).await.unwrap();
+        assert_eq!(
+            topic,
+            ForumTopic {
+                message_thread_id: 42,
+                name: "Releases".to_string(),
+                icon_color: FORUM_TOPIC_ICON_COLORS[0],
+                icon_custom_emoji_id: None,
+            }
+        );
+    }
+}
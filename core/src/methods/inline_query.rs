@@ -0,0 +1,36 @@
+use crate::{
+    bot::Bot,
+    models::web_app::SentWebAppMessage,
+    params::inline_query_params::{AnswerInlineQueryParams, AnswerWebAppQueryParams},
+    requests, TelegrapherError,
+};
+
+impl Bot {
+    /// Send answers to an inline query, no more than 50 results are allowed per query.
+    /// [The official docs](https://core.telegram.org/bots/api#answerinlinequery)
+    pub async fn answer_inline_query(
+        &self,
+        params: &AnswerInlineQueryParams,
+    ) -> Result<bool, TelegrapherError> {
+        requests::post_request::<AnswerInlineQueryParams, bool>(
+            self,
+            "answerInlineQuery",
+            Some(params),
+        )
+        .await
+    }
+
+    /// Set the result of an interaction with a Web App and send a message on behalf of the user.
+    /// [The official docs](https://core.telegram.org/bots/api#answerwebappquery)
+    pub async fn answer_web_app_query(
+        &self,
+        params: &AnswerWebAppQueryParams,
+    ) -> Result<SentWebAppMessage, TelegrapherError> {
+        requests::post_request::<AnswerWebAppQueryParams, SentWebAppMessage>(
+            self,
+            "answerWebAppQuery",
+            Some(params),
+        )
+        .await
+    }
+}
@@ -1,64 +1,116 @@
-use crate::params::media_params::SendDocumentParams;
+use crate::params::media_params::{
+    EditMessageMediaParams, SendAnimationParams, SendAudioParams, SendDocumentParams,
+    SendMediaGroupParams, SendStickerParams, SendVideoNoteParams, SendVideoParams, SendVoiceParams,
+};
 use crate::{
     bot::Bot,
-    models::{message::Message, sticker::FileUpload},
+    models::message::{EditedMessage, Message},
     params::media_params::SendPhotoParams,
-    requests,
-    responses::MethodResponse,
-    FileType, TelegrapherError,
+    requests, TelegrapherError,
 };
 
 impl Bot {
-    pub async fn send_photo(
+    pub async fn send_photo(&self, params: &SendPhotoParams) -> Result<Message, TelegrapherError> {
+        requests::post_multi_part_request::<SendPhotoParams, Message>(self, "sendPhoto", params)
+            .await
+    }
+
+    pub async fn send_document(
         &self,
-        params: &SendPhotoParams,
-    ) -> Result<MethodResponse<Message>, TelegrapherError> {
-        if let FileUpload::String(_) = &params.photo {
-            return requests::post_request::<SendPhotoParams, Message>(
-                "sendPhoto",
-                self.token(),
-                Some(params),
-            )
-            .await;
-        }
+        params: &SendDocumentParams,
+    ) -> Result<Message, TelegrapherError> {
+        requests::post_multi_part_request::<SendDocumentParams, Message>(
+            self,
+            "sendDocument",
+            params,
+        )
+        .await
+    }
+
+    /// Send a video, mp4 videos can be sent with `supports_streaming`.
+    /// [The official docs](https://core.telegram.org/bots/api#sendvideo)
+    pub async fn send_video(&self, params: &SendVideoParams) -> Result<Message, TelegrapherError> {
+        requests::post_multi_part_request::<SendVideoParams, Message>(self, "sendVideo", params)
+            .await
+    }
+
+    /// Send an audio file shown in the music player, in .mp3 or .m4a format.
+    /// [The official docs](https://core.telegram.org/bots/api#sendaudio)
+    pub async fn send_audio(&self, params: &SendAudioParams) -> Result<Message, TelegrapherError> {
+        requests::post_multi_part_request::<SendAudioParams, Message>(self, "sendAudio", params)
+            .await
+    }
+
+    /// Send a voice message, in .ogg with opus, .mp3 or .m4a format.
+    /// [The official docs](https://core.telegram.org/bots/api#sendvoice)
+    pub async fn send_voice(&self, params: &SendVoiceParams) -> Result<Message, TelegrapherError> {
+        requests::post_multi_part_request::<SendVoiceParams, Message>(self, "sendVoice", params)
+            .await
+    }
 
-        let input_file = match &params.photo {
-            FileUpload::InputFile(path) => path,
-            _ => return Err(TelegrapherError::from("Invalid file path")),
-        };
-        requests::post_multi_part_request::<SendPhotoParams, Message>(
-            "sendPhoto",
-            self.token(),
-            Some(params),
-            &input_file.path,
-            &FileType::Photo,
+    /// Send a gif or a h.264/mpeg-4 avc video without sound.
+    /// [The official docs](https://core.telegram.org/bots/api#sendanimation)
+    pub async fn send_animation(
+        &self,
+        params: &SendAnimationParams,
+    ) -> Result<Message, TelegrapherError> {
+        requests::post_multi_part_request::<SendAnimationParams, Message>(
+            self,
+            "sendAnimation",
+            params,
         )
         .await
     }
 
-    pub async fn send_document(
+    /// Send a rounded square mp4 video of up to one minute.
+    /// [The official docs](https://core.telegram.org/bots/api#sendvideonote)
+    pub async fn send_video_note(
         &self,
-        params: &SendDocumentParams,
-    ) -> Result<MethodResponse<Message>, TelegrapherError> {
-        if let FileUpload::String(_) = &params.document {
-            return requests::post_request::<SendDocumentParams, Message>(
-                "sendDocument",
-                self.token(),
-                Some(params),
-            )
-            .await;
-        }
+        params: &SendVideoNoteParams,
+    ) -> Result<Message, TelegrapherError> {
+        requests::post_multi_part_request::<SendVideoNoteParams, Message>(
+            self,
+            "sendVideoNote",
+            params,
+        )
+        .await
+    }
 
-        let input_file = match &params.document {
-            FileUpload::InputFile(path) => path,
-            _ => return Err(TelegrapherError::from("Invalid file path")),
-        };
-        requests::post_multi_part_request::<SendDocumentParams, Message>(
-            "sendDocument",
-            self.token(),
-            Some(params),
-            &input_file.path,
-            &FileType::Document,
+    /// Send a static .webp, animated .tgs or video .webm sticker.
+    /// [The official docs](https://core.telegram.org/bots/api#sendsticker)
+    pub async fn send_sticker(
+        &self,
+        params: &SendStickerParams,
+    ) -> Result<Message, TelegrapherError> {
+        requests::post_multi_part_request::<SendStickerParams, Message>(self, "sendSticker", params)
+            .await
+    }
+
+    /// Send 2-10 photos, videos, documents or audios as an album, returns the sent messages.
+    /// [The official docs](https://core.telegram.org/bots/api#sendmediagroup)
+    pub async fn send_media_group(
+        &self,
+        params: &SendMediaGroupParams,
+    ) -> Result<Vec<Message>, TelegrapherError> {
+        requests::post_multi_part_request::<SendMediaGroupParams, Vec<Message>>(
+            self,
+            "sendMediaGroup",
+            params,
+        )
+        .await
+    }
+
+    /// Replace the media of a message, an album item can only be replaced with the same media type.
+    /// An inline message edited by `inline_message_id` returns `EditedMessage::Inline`.
+    /// [The official docs](https://core.telegram.org/bots/api#editmessagemedia)
+    pub async fn edit_message_media(
+        &self,
+        params: &EditMessageMediaParams,
+    ) -> Result<EditedMessage, TelegrapherError> {
+        requests::post_multi_part_request::<EditMessageMediaParams, EditedMessage>(
+            self,
+            "editMessageMedia",
+            params,
         )
         .await
     }
@@ -1,14 +1,22 @@
 use crate::params::message_params::EditMessageReplyMarkupParams;
 use crate::{
     bot::Bot,
-    models::message::Message,
+    models::{
+        message::{EditedMessage, Message, MessageId},
+        poll::Poll,
+    },
     params::{
         callback_query_param::AnswerCallbackQueryParams,
-        message_params::{DeleteMessageParams, EditMessageTextParams, SendMessageParams},
+        chat_params::ChatIdParams,
+        message_params::{
+            CopyMessageParams, CopyMessagesParams, DeleteMessageParams, DeleteMessagesParams,
+            EditMessageLiveLocationParams, EditMessageTextParams, ForwardMessageParams,
+            ForwardMessagesParams, PinChatMessageParams, SendContactParams, SendDiceParams,
+            SendLocationParams, SendMessageParams, SendPollParams, SendVenueParams,
+            StopMessageLiveLocationParams, StopPollParams, UnpinChatMessageParams,
+        },
     },
-    requests,
-    responses::MethodResponse,
-    TelegrapherError,
+    requests, TelegrapherError,
 };
 
 impl Bot {
@@ -28,28 +36,19 @@ impl Bot {
     pub async fn send_message_throttled(
         &self,
         params: &SendMessageParams,
-    ) -> Result<MethodResponse<Message>, TelegrapherError> {
-        if (self.get_message_send_permissions(params.chat_id).await).is_ok() {
-            requests::post_request::<SendMessageParams, Message>(
-                "sendMessage",
-                self.token(),
-                Some(params),
-            )
+    ) -> Result<Message, TelegrapherError> {
+        self.get_message_send_permissions(params.chat_id).await?;
+        requests::post_request::<SendMessageParams, Message>(self, "sendMessage", Some(params))
             .await
-        } else {
-            Err(TelegrapherError::from(
-                "failed to get permission to send message to the chat",
-            ))
-        }
     }
 
     pub async fn edit_message(
         &self,
         params: &EditMessageTextParams,
-    ) -> Result<MethodResponse<Message>, TelegrapherError> {
+    ) -> Result<Message, TelegrapherError> {
         requests::post_request::<EditMessageTextParams, Message>(
+            self,
             "editMessageText",
-            self.token(),
             Some(params),
         )
         .await
@@ -58,10 +57,10 @@ impl Bot {
     pub async fn edit_message_caption(
         &self,
         params: &EditMessageTextParams,
-    ) -> Result<MethodResponse<Message>, TelegrapherError> {
+    ) -> Result<Message, TelegrapherError> {
         requests::post_request::<EditMessageTextParams, Message>(
+            self,
             "editMessageCaption",
-            self.token(),
             Some(params),
         )
         .await
@@ -70,10 +69,10 @@ impl Bot {
     pub async fn edit_message_reply_markup(
         &self,
         params: &EditMessageReplyMarkupParams,
-    ) -> Result<MethodResponse<Message>, TelegrapherError> {
+    ) -> Result<Message, TelegrapherError> {
         requests::post_request::<EditMessageReplyMarkupParams, Message>(
+            self,
             "editMessageReplyMarkup",
-            self.token(),
             Some(params),
         )
         .await
@@ -82,22 +81,196 @@ impl Bot {
     pub async fn delete_message(
         &self,
         params: &DeleteMessageParams,
-    ) -> Result<MethodResponse<bool>, TelegrapherError> {
-        requests::post_request::<DeleteMessageParams, bool>(
-            "deleteMessage",
-            self.token(),
+    ) -> Result<bool, TelegrapherError> {
+        requests::post_request::<DeleteMessageParams, bool>(self, "deleteMessage", Some(params))
+            .await
+    }
+
+    /// Forward a message of any kind, service messages can't be forwarded.
+    /// [The official docs](https://core.telegram.org/bots/api#forwardmessage)
+    pub async fn forward_message(
+        &self,
+        params: &ForwardMessageParams,
+    ) -> Result<Message, TelegrapherError> {
+        requests::post_request::<ForwardMessageParams, Message>(
+            self,
+            "forwardMessage",
+            Some(params),
+        )
+        .await
+    }
+
+    /// Forward several messages at once, album grouping is kept.
+    /// Messages that can't be found or forwarded are skipped.
+    /// [The official docs](https://core.telegram.org/bots/api#forwardmessages)
+    pub async fn forward_messages(
+        &self,
+        params: &ForwardMessagesParams,
+    ) -> Result<Vec<MessageId>, TelegrapherError> {
+        requests::post_request::<ForwardMessagesParams, Vec<MessageId>>(
+            self,
+            "forwardMessages",
+            Some(params),
+        )
+        .await
+    }
+
+    /// Copy a message without a link to the original message.
+    /// [The official docs](https://core.telegram.org/bots/api#copymessage)
+    pub async fn copy_message(
+        &self,
+        params: &CopyMessageParams,
+    ) -> Result<MessageId, TelegrapherError> {
+        requests::post_request::<CopyMessageParams, MessageId>(self, "copyMessage", Some(params))
+            .await
+    }
+
+    /// Copy several messages at once, album grouping is kept.
+    /// Messages that can't be found or copied are skipped.
+    /// [The official docs](https://core.telegram.org/bots/api#copymessages)
+    pub async fn copy_messages(
+        &self,
+        params: &CopyMessagesParams,
+    ) -> Result<Vec<MessageId>, TelegrapherError> {
+        requests::post_request::<CopyMessagesParams, Vec<MessageId>>(
+            self,
+            "copyMessages",
+            Some(params),
+        )
+        .await
+    }
+
+    /// Send a point on the map, pass `live_period` to send a live location.
+    /// [The official docs](https://core.telegram.org/bots/api#sendlocation)
+    pub async fn send_location(
+        &self,
+        params: &SendLocationParams,
+    ) -> Result<Message, TelegrapherError> {
+        requests::post_request::<SendLocationParams, Message>(self, "sendLocation", Some(params))
+            .await
+    }
+
+    /// Move a live location until its `live_period` expires or it is stopped.
+    /// An inline message edited by `inline_message_id` returns `EditedMessage::Inline`.
+    /// [The official docs](https://core.telegram.org/bots/api#editmessagelivelocation)
+    pub async fn edit_message_live_location(
+        &self,
+        params: &EditMessageLiveLocationParams,
+    ) -> Result<EditedMessage, TelegrapherError> {
+        requests::post_request::<EditMessageLiveLocationParams, EditedMessage>(
+            self,
+            "editMessageLiveLocation",
+            Some(params),
+        )
+        .await
+    }
+
+    /// Stop updating a live location before its `live_period` expires.
+    /// An inline message stopped by `inline_message_id` returns `EditedMessage::Inline`.
+    /// [The official docs](https://core.telegram.org/bots/api#stopmessagelivelocation)
+    pub async fn stop_message_live_location(
+        &self,
+        params: &StopMessageLiveLocationParams,
+    ) -> Result<EditedMessage, TelegrapherError> {
+        requests::post_request::<StopMessageLiveLocationParams, EditedMessage>(
+            self,
+            "stopMessageLiveLocation",
+            Some(params),
+        )
+        .await
+    }
+
+    /// Send information about a venue.
+    /// [The official docs](https://core.telegram.org/bots/api#sendvenue)
+    pub async fn send_venue(&self, params: &SendVenueParams) -> Result<Message, TelegrapherError> {
+        requests::post_request::<SendVenueParams, Message>(self, "sendVenue", Some(params)).await
+    }
+
+    /// Send a phone contact.
+    /// [The official docs](https://core.telegram.org/bots/api#sendcontact)
+    pub async fn send_contact(
+        &self,
+        params: &SendContactParams,
+    ) -> Result<Message, TelegrapherError> {
+        requests::post_request::<SendContactParams, Message>(self, "sendContact", Some(params))
+            .await
+    }
+
+    /// Send an animated emoji with a random value.
+    /// [The official docs](https://core.telegram.org/bots/api#senddice)
+    pub async fn send_dice(&self, params: &SendDiceParams) -> Result<Message, TelegrapherError> {
+        requests::post_request::<SendDiceParams, Message>(self, "sendDice", Some(params)).await
+    }
+
+    /// Send a native poll, use `PollType::Quiz` with `correct_option_id` for a quiz.
+    /// [The official docs](https://core.telegram.org/bots/api#sendpoll)
+    pub async fn send_poll(&self, params: &SendPollParams) -> Result<Message, TelegrapherError> {
+        requests::post_request::<SendPollParams, Message>(self, "sendPoll", Some(params)).await
+    }
+
+    /// Stop a poll sent by the bot, returns the final results.
+    /// [The official docs](https://core.telegram.org/bots/api#stoppoll)
+    pub async fn stop_poll(&self, params: &StopPollParams) -> Result<Poll, TelegrapherError> {
+        requests::post_request::<StopPollParams, Poll>(self, "stopPoll", Some(params)).await
+    }
+
+    /// Delete 1-100 messages at once, messages that can't be found or deleted are skipped.
+    /// [The official docs](https://core.telegram.org/bots/api#deletemessages)
+    pub async fn delete_messages(
+        &self,
+        params: &DeleteMessagesParams,
+    ) -> Result<bool, TelegrapherError> {
+        if params.message_ids.is_empty() || params.message_ids.len() > 100 {
+            return Err(TelegrapherError::InvalidParams(format!(
+                "deleteMessages takes 1-100 message ids, got {}",
+                params.message_ids.len()
+            )));
+        }
+        requests::post_request::<DeleteMessagesParams, bool>(self, "deleteMessages", Some(params))
+            .await
+    }
+
+    /// Add a message to the list of pinned messages of a chat.
+    /// [The official docs](https://core.telegram.org/bots/api#pinchatmessage)
+    pub async fn pin_chat_message(
+        &self,
+        params: &PinChatMessageParams,
+    ) -> Result<bool, TelegrapherError> {
+        requests::post_request::<PinChatMessageParams, bool>(self, "pinChatMessage", Some(params))
+            .await
+    }
+
+    /// Remove a message from the list of pinned messages of a chat.
+    /// [The official docs](https://core.telegram.org/bots/api#unpinchatmessage)
+    pub async fn unpin_chat_message(
+        &self,
+        params: &UnpinChatMessageParams,
+    ) -> Result<bool, TelegrapherError> {
+        requests::post_request::<UnpinChatMessageParams, bool>(
+            self,
+            "unpinChatMessage",
             Some(params),
         )
         .await
     }
 
+    /// Clear the list of pinned messages of a chat.
+    /// [The official docs](https://core.telegram.org/bots/api#unpinallchatmessages)
+    pub async fn unpin_all_chat_messages(
+        &self,
+        params: &ChatIdParams,
+    ) -> Result<bool, TelegrapherError> {
+        requests::post_request::<ChatIdParams, bool>(self, "unpinAllChatMessages", Some(params))
+            .await
+    }
+
     pub async fn answer_callback_query(
         &self,
         params: &AnswerCallbackQueryParams,
-    ) -> Result<MethodResponse<bool>, TelegrapherError> {
+    ) -> Result<bool, TelegrapherError> {
         requests::post_request::<AnswerCallbackQueryParams, bool>(
+            self,
             "answerCallbackQuery",
-            self.token(),
             Some(params),
         )
         .await
@@ -116,6 +289,94 @@ mod tests {
 
     use super::*;
 
+    #[tokio::test]
+    async fn test_copy_messages() {
+        use axum::{extract::Path, routing::post, Json, Router};
+        use serde_json::{json, Value};
+
+        use crate::params::message_params::{CopyMessageParamsBuilder, CopyMessagesParamsBuilder};
+
+        // the copies get the ids of the originals plus 100
+        let app = Router::new().route(
+            "/:token/:method",
+            post(
+                |Path((_, method)): Path<(String, String)>, Json(params): Json<Value>| async move {
+                    let copy_id = |id: &Value| json!({"message_id": id.as_i64().unwrap() + 100});
+                    let result = match method.as_str() {
+                        "copyMessage" => copy_id(&params["message_id"]),
+                        _ => params["message_ids"]
+                            .as_array()
+                            .unwrap()
+                            .iter()
+                            .map(copy_id)
+                            .collect(),
+                    };
+                    Json(json!({"ok": true, "result": result}))
+                },
+            ),
+        );
+        let bot = Bot::new("123:abc", 1).with_api_url(&crate::test_server::serve(app).await);
+
+        let params = CopyMessageParamsBuilder::default()
+            .chat_id(1)
+            .from_chat_id(2)
+            .message_id(5)
+            .build()
+            .unwrap();
+        let copy = bot.copy_message(&params).await.unwrap();
+        assert_eq!(copy, MessageId { message_id: 105 });
+
+        let params = CopyMessagesParamsBuilder::default()
+            .chat_id(1)
+            .from_chat_id(2)
+            .message_ids(vec![5, 6, 7])
+            .build()
+            .unwrap();
+        let copies = bot.copy_messages(&params).await.unwrap();
+        let ids: Vec<i64> = copies.iter().map(|copy| copy.message_id).collect();
+        assert_eq!(ids, vec![105, 106, 107]);
+    }
+
+    #[tokio::test]
+    async fn test_edit_inline_live_location() {
+        use axum::{routing::post, Json, Router};
+        use serde_json::{json, Value};
+
+        use crate::params::message_params::EditMessageLiveLocationParamsBuilder;
+
+        // inline messages are edited without a chat, telegram answers them with `true`
+        let app = Router::new().route(
+            "/:token/:method",
+            post(|Json(params): Json<Value>| async move {
+                let result = match params.get("chat_id") {
+                    Some(chat_id) => json!({"message_id": 7, "date": 0, "chat": {"id": chat_id, "type": "private"}}),
+                    None => json!(true),
+                };
+                Json(json!({"ok": true, "result": result}))
+            }),
+        );
+        let bot = Bot::new("123:abc", 1).with_api_url(&crate::test_server::serve(app).await);
+
+        let params = EditMessageLiveLocationParamsBuilder::default()
+            .inline_message_id("AgAAAN".to_string())
+            .latitude(52.52)
+            .longitude(13.40)
+            .build()
+            .unwrap();
+        let edited = bot.edit_message_live_location(&params).await.unwrap();
+        assert_eq!(edited, EditedMessage::Inline(true));
+
+        let params = EditMessageLiveLocationParamsBuilder::default()
+            .chat_id(1)
+            .message_id(7)
+            .latitude(52.52)
+            .longitude(13.40)
+            .build()
+            .unwrap();
+        let edited = bot.edit_message_live_location(&params).await.unwrap();
+        assert_eq!(edited.message().map(|message| message.message_id), Some(7));
+    }
+
     #[tokio::test]
     async fn test_send_message() {
         let bot = Bot::new("6616659571:AAEr0TdwPXBnvHQl_VJj5Z6wh-p3uUDNbOw", 1);
@@ -145,4 +406,15 @@ mod tests {
         let message = bot.send_message_throttled(&params).await.unwrap();
         println!("{:?}", message);
     }
+
+    #[tokio::test]
+    async fn test_delete_messages_limit() {
+        let bot = Bot::new("123:abc", 1).with_api_url("http://127.0.0.1:9");
+        let params = DeleteMessagesParams {
+            chat_id: 1,
+            message_ids: (1..=101).collect(),
+        };
+        let result = bot.delete_messages(&params).await;
+        assert!(matches!(result, Err(TelegrapherError::InvalidParams(_))));
+    }
 }
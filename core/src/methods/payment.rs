@@ -0,0 +1,77 @@
+use crate::{
+    bot::Bot,
+    models::message::Message,
+    params::payment_params::{
+        AnswerPreCheckoutQueryParams, AnswerShippingQueryParams, CreateInvoiceLinkParams,
+        RefundStarPaymentParams, SendInvoiceParams,
+    },
+    requests, TelegrapherError,
+};
+
+impl Bot {
+    /// Send an invoice.
+    /// [The official docs](https://core.telegram.org/bots/api#sendinvoice)
+    pub async fn send_invoice(
+        &self,
+        params: &SendInvoiceParams,
+    ) -> Result<Message, TelegrapherError> {
+        requests::post_request::<SendInvoiceParams, Message>(self, "sendInvoice", Some(params))
+            .await
+    }
+
+    /// Create a link for an invoice.
+    /// [The official docs](https://core.telegram.org/bots/api#createinvoicelink)
+    pub async fn create_invoice_link(
+        &self,
+        params: &CreateInvoiceLinkParams,
+    ) -> Result<String, TelegrapherError> {
+        requests::post_request::<CreateInvoiceLinkParams, String>(
+            self,
+            "createInvoiceLink",
+            Some(params),
+        )
+        .await
+    }
+
+    /// Reply to a shipping query of an invoice with a flexible price.
+    /// [The official docs](https://core.telegram.org/bots/api#answershippingquery)
+    pub async fn answer_shipping_query(
+        &self,
+        params: &AnswerShippingQueryParams,
+    ) -> Result<bool, TelegrapherError> {
+        requests::post_request::<AnswerShippingQueryParams, bool>(
+            self,
+            "answerShippingQuery",
+            Some(params),
+        )
+        .await
+    }
+
+    /// Confirm or cancel an order, telegram waits for the answer at most 10 seconds.
+    /// [The official docs](https://core.telegram.org/bots/api#answerprecheckoutquery)
+    pub async fn answer_pre_checkout_query(
+        &self,
+        params: &AnswerPreCheckoutQueryParams,
+    ) -> Result<bool, TelegrapherError> {
+        requests::post_request::<AnswerPreCheckoutQueryParams, bool>(
+            self,
+            "answerPreCheckoutQuery",
+            Some(params),
+        )
+        .await
+    }
+
+    /// Refund a successful payment in Telegram Stars.
+    /// [The official docs](https://core.telegram.org/bots/api#refundstarpayment)
+    pub async fn refund_star_payment(
+        &self,
+        params: &RefundStarPaymentParams,
+    ) -> Result<bool, TelegrapherError> {
+        requests::post_request::<RefundStarPaymentParams, bool>(
+            self,
+            "refundStarPayment",
+            Some(params),
+        )
+        .await
+    }
+}
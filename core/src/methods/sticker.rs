@@ -0,0 +1,225 @@
+use crate::{
+    bot::Bot,
+    models::{file::File, sticker::Sticker, sticker_set::StickerSet},
+    params::sticker_params::{
+        AddStickerToSetParams, CreateNewStickerSetParams, DeleteStickerFromSetParams,
+        DeleteStickerSetParams, GetCustomEmojiStickersParams, GetStickerSetParams,
+        ReplaceStickerInSetParams, SetCustomEmojiStickerSetThumbnailParams,
+        SetStickerEmojiListParams, SetStickerKeywordsParams, SetStickerMaskPositionParams,
+        SetStickerPositionInSetParams, SetStickerSetThumbnailParams, SetStickerSetTitleParams,
+        UploadStickerFileParams,
+    },
+    requests, TelegrapherError,
+};
+
+impl Bot {
+    /// Get a sticker set by its name.
+    /// [The official docs](https://core.telegram.org/bots/api#getstickerset)
+    pub async fn get_sticker_set(
+        &self,
+        params: &GetStickerSetParams,
+    ) -> Result<StickerSet, TelegrapherError> {
+        requests::post_request::<GetStickerSetParams, StickerSet>(
+            self,
+            "getStickerSet",
+            Some(params),
+        )
+        .await
+    }
+
+    /// Get information about custom emoji stickers by their identifiers.
+    /// [The official docs](https://core.telegram.org/bots/api#getcustomemojistickers)
+    pub async fn get_custom_emoji_stickers(
+        &self,
+        params: &GetCustomEmojiStickersParams,
+    ) -> Result<Vec<Sticker>, TelegrapherError> {
+        requests::post_request::<GetCustomEmojiStickersParams, Vec<Sticker>>(
+            self,
+            "getCustomEmojiStickers",
+            Some(params),
+        )
+        .await
+    }
+
+    /// Upload a sticker file for later use in `create_new_sticker_set`, `add_sticker_to_set` or `replace_sticker_in_set`.
+    /// [The official docs](https://core.telegram.org/bots/api#uploadstickerfile)
+    pub async fn upload_sticker_file(
+        &self,
+        params: &UploadStickerFileParams,
+    ) -> Result<File, TelegrapherError> {
+        requests::post_multi_part_request::<UploadStickerFileParams, File>(
+            self,
+            "uploadStickerFile",
+            params,
+        )
+        .await
+    }
+
+    /// Create a new sticker set owned by a user, new sticker files are uploaded in the same request.
+    /// [The official docs](https://core.telegram.org/bots/api#createnewstickerset)
+    pub async fn create_new_sticker_set(
+        &self,
+        params: &CreateNewStickerSetParams,
+    ) -> Result<bool, TelegrapherError> {
+        requests::post_multi_part_request::<CreateNewStickerSetParams, bool>(
+            self,
+            "createNewStickerSet",
+            params,
+        )
+        .await
+    }
+
+    /// Add a new sticker to a set created by the bot.
+    /// [The official docs](https://core.telegram.org/bots/api#addstickertoset)
+    pub async fn add_sticker_to_set(
+        &self,
+        params: &AddStickerToSetParams,
+    ) -> Result<bool, TelegrapherError> {
+        requests::post_multi_part_request::<AddStickerToSetParams, bool>(
+            self,
+            "addStickerToSet",
+            params,
+        )
+        .await
+    }
+
+    /// Move a sticker in a set created by the bot.
+    /// [The official docs](https://core.telegram.org/bots/api#setstickerpositioninset)
+    pub async fn set_sticker_position_in_set(
+        &self,
+        params: &SetStickerPositionInSetParams,
+    ) -> Result<bool, TelegrapherError> {
+        requests::post_request::<SetStickerPositionInSetParams, bool>(
+            self,
+            "setStickerPositionInSet",
+            Some(params),
+        )
+        .await
+    }
+
+    /// Delete a sticker from a set created by the bot.
+    /// [The official docs](https://core.telegram.org/bots/api#deletestickerfromset)
+    pub async fn delete_sticker_from_set(
+        &self,
+        params: &DeleteStickerFromSetParams,
+    ) -> Result<bool, TelegrapherError> {
+        requests::post_request::<DeleteStickerFromSetParams, bool>(
+            self,
+            "deleteStickerFromSet",
+            Some(params),
+        )
+        .await
+    }
+
+    /// Replace an existing sticker in a set with a new one.
+    /// [The official docs](https://core.telegram.org/bots/api#replacestickerinset)
+    pub async fn replace_sticker_in_set(
+        &self,
+        params: &ReplaceStickerInSetParams,
+    ) -> Result<bool, TelegrapherError> {
+        requests::post_multi_part_request::<ReplaceStickerInSetParams, bool>(
+            self,
+            "replaceStickerInSet",
+            params,
+        )
+        .await
+    }
+
+    /// Change the emoji of a regular or custom emoji sticker.
+    /// [The official docs](https://core.telegram.org/bots/api#setstickeremojilist)
+    pub async fn set_sticker_emoji_list(
+        &self,
+        params: &SetStickerEmojiListParams,
+    ) -> Result<bool, TelegrapherError> {
+        requests::post_request::<SetStickerEmojiListParams, bool>(
+            self,
+            "setStickerEmojiList",
+            Some(params),
+        )
+        .await
+    }
+
+    /// Change the search keywords of a regular or custom emoji sticker.
+    /// [The official docs](https://core.telegram.org/bots/api#setstickerkeywords)
+    pub async fn set_sticker_keywords(
+        &self,
+        params: &SetStickerKeywordsParams,
+    ) -> Result<bool, TelegrapherError> {
+        requests::post_request::<SetStickerKeywordsParams, bool>(
+            self,
+            "setStickerKeywords",
+            Some(params),
+        )
+        .await
+    }
+
+    /// Change the mask position of a mask sticker.
+    /// [The official docs](https://core.telegram.org/bots/api#setstickermaskposition)
+    pub async fn set_sticker_mask_position(
+        &self,
+        params: &SetStickerMaskPositionParams,
+    ) -> Result<bool, TelegrapherError> {
+        requests::post_request::<SetStickerMaskPositionParams, bool>(
+            self,
+            "setStickerMaskPosition",
+            Some(params),
+        )
+        .await
+    }
+
+    /// Set the title of a sticker set created by the bot.
+    /// [The official docs](https://core.telegram.org/bots/api#setstickersettitle)
+    pub async fn set_sticker_set_title(
+        &self,
+        params: &SetStickerSetTitleParams,
+    ) -> Result<bool, TelegrapherError> {
+        requests::post_request::<SetStickerSetTitleParams, bool>(
+            self,
+            "setStickerSetTitle",
+            Some(params),
+        )
+        .await
+    }
+
+    /// Set the thumbnail of a regular or mask sticker set.
+    /// [The official docs](https://core.telegram.org/bots/api#setstickersetthumbnail)
+    pub async fn set_sticker_set_thumbnail(
+        &self,
+        params: &SetStickerSetThumbnailParams,
+    ) -> Result<bool, TelegrapherError> {
+        requests::post_multi_part_request::<SetStickerSetThumbnailParams, bool>(
+            self,
+            "setStickerSetThumbnail",
+            params,
+        )
+        .await
+    }
+
+    /// Set the thumbnail of a custom emoji sticker set.
+    /// [The official docs](https://core.telegram.org/bots/api#setcustomemojistickersetthumbnail)
+    pub async fn set_custom_emoji_sticker_set_thumbnail(
+        &self,
+        params: &SetCustomEmojiStickerSetThumbnailParams,
+    ) -> Result<bool, TelegrapherError> {
+        requests::post_request::<SetCustomEmojiStickerSetThumbnailParams, bool>(
+            self,
+            "setCustomEmojiStickerSetThumbnail",
+            Some(params),
+        )
+        .await
+    }
+
+    /// Delete a sticker set created by the bot.
+    /// [The official docs](https://core.telegram.org/bots/api#deletestickerset)
+    pub async fn delete_sticker_set(
+        &self,
+        params: &DeleteStickerSetParams,
+    ) -> Result<bool, TelegrapherError> {
+        requests::post_request::<DeleteStickerSetParams, bool>(
+            self,
+            "deleteStickerSet",
+            Some(params),
+        )
+        .await
+    }
+}
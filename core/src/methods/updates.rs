@@ -1,19 +1,14 @@
 use crate::params::updates_params::GetUpdatesParams;
 use crate::requests;
-use crate::responses::MethodResponse;
 use crate::{bot::Bot, models::update::Update, TelegrapherError};
 
 impl Bot {
     pub async fn get_updates(
         &self,
         params: &GetUpdatesParams,
-    ) -> Result<MethodResponse<Vec<Update>>, TelegrapherError> {
-        requests::post_request::<GetUpdatesParams, Vec<Update>>(
-            "getUpdates",
-            self.token(),
-            Some(params),
-        )
-        .await
+    ) -> Result<Vec<Update>, TelegrapherError> {
+        requests::post_request::<GetUpdatesParams, Vec<Update>>(self, "getUpdates", Some(params))
+            .await
     }
 }
 
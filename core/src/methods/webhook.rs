@@ -2,18 +2,13 @@ use crate::{
     bot::Bot,
     models::webhook::WebhookInfo,
     params::webhook_param::{DeleteWebhookParams, SetWebhookParams},
-    requests,
-    responses::MethodResponse,
-    TelegrapherError,
+    requests, TelegrapherError,
 };
 
 impl Bot {
-    /// Set a webhook for the bot.
-    pub async fn set_webhook(
-        &self,
-        params: &SetWebhookParams,
-    ) -> Result<MethodResponse<bool>, TelegrapherError> {
-        requests::post_request::<SetWebhookParams, bool>("setWebhook", self.token(), Some(params))
+    /// Set a webhook for the bot, a self-signed certificate is uploaded with the request.
+    pub async fn set_webhook(&self, params: &SetWebhookParams) -> Result<bool, TelegrapherError> {
+        requests::post_multi_part_request::<SetWebhookParams, bool>(self, "setWebhook", params)
             .await
     }
 
@@ -21,17 +16,13 @@ impl Bot {
     pub async fn delete_webhook(
         &self,
         params: &DeleteWebhookParams,
-    ) -> Result<MethodResponse<bool>, TelegrapherError> {
-        requests::post_request::<DeleteWebhookParams, bool>(
-            "deleteWebhook",
-            self.token(),
-            Some(params),
-        )
-        .await
+    ) -> Result<bool, TelegrapherError> {
+        requests::post_request::<DeleteWebhookParams, bool>(self, "deleteWebhook", Some(params))
+            .await
     }
 
     /// Get the webhook info for the bot.
-    pub async fn get_webhook_info(&self) -> Result<MethodResponse<WebhookInfo>, TelegrapherError> {
-        requests::post_request::<(), WebhookInfo>("getWebhookInfo", self.token(), None).await
+    pub async fn get_webhook_info(&self) -> Result<WebhookInfo, TelegrapherError> {
+        requests::post_request::<(), WebhookInfo>(self, "getWebhookInfo", None).await
     }
 }
@@ -0,0 +1,173 @@
+use std::time::Duration;
+
+use futures::future::BoxFuture;
+use reqwest::StatusCode;
+use serde_json::Value;
+
+use crate::{TelegrapherError, TelegrapherResult};
+
+/// A Bot API call as seen by the middlewares.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ApiRequest {
+    /// Bot API method name, e.g. `sendMessage`
+    pub method: String,
+    /// params of the method, `Value::Null` for methods without params.
+    /// Files of multipart requests are not part of it.
+    pub params: Value,
+}
+
+/// The outcome of one attempt of a Bot API call.
+#[derive(Debug)]
+pub struct ApiResponse<'a> {
+    /// attempt number, starts from 1 and grows when the request is retried
+    pub attempt: u32,
+    /// time from sending the request to receiving the whole response
+    pub elapsed: Duration,
+    /// http status, `None` if no response was received
+    pub status: Option<StatusCode>,
+    /// raw response body
+    pub body: Option<&'a str>,
+    /// network, decode or api error of this attempt, `None` if the call succeeded
+    pub error: Option<&'a TelegrapherError>,
+}
+
+/// Interceptor around every Bot API call of a bot, add it with `Bot::with_middleware`.
+///
+/// Middlewares run in the order they were added. `before_request` runs once per call, before the
+/// first attempt, `after_response` runs after every attempt, including retried ones.
+pub trait Middleware: Send + Sync {
+    /// Inspect or modify the outgoing method and params.
+    /// Returning an error cancels the call, the error is returned to the caller.
+    fn before_request<'a>(
+        &'a self,
+        _request: &'a mut ApiRequest,
+    ) -> BoxFuture<'a, TelegrapherResult<()>> {
+        Box::pin(async { Ok(()) })
+    }
+
+    /// Observe the response of an attempt.
+    fn after_response<'a>(
+        &'a self,
+        _request: &'a ApiRequest,
+        _response: &'a ApiResponse<'a>,
+    ) -> BoxFuture<'a, ()> {
+        Box::pin(async {})
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::{Arc, Mutex};
+
+    use axum::{extract::Path, routing::post, Json, Router};
+    use serde_json::json;
+
+    use crate::{
+        bot::Bot, models::message::Message, params::message_params::SendMessageParamsBuilder,
+    };
+
+    use super::*;
+
+    /// Bot API stand-in, echoes the params of sendMessage back in the message text.
+    async fn start_api_server() -> String {
+        let app = Router::new().route(
+            "/:token/:method",
+            post(
+                |Path((_, method)): Path<(String, String)>, Json(params): Json<Value>| async move {
+                    Json(json!({
+                        "ok": true,
+                        "result": {
+                            "message_id": 1,
+                            "date": 0,
+                            "chat": {"id": params["chat_id"], "type": "private"},
+                            "text": format!("{} {}", method, params),
+                        }
+                    }))
+                },
+            ),
+        );
+        crate::test_server::serve(app).await
+    }
+
+    struct DefaultParseMode;
+
+    impl Middleware for DefaultParseMode {
+        fn before_request<'a>(
+            &'a self,
+            request: &'a mut ApiRequest,
+        ) -> BoxFuture<'a, TelegrapherResult<()>> {
+            Box::pin(async move {
+                if let Some(params) = request.params.as_object_mut() {
+                    params
+                        .entry("parse_mode")
+                        .or_insert_with(|| Value::from("HTML"));
+                }
+                Ok(())
+            })
+        }
+    }
+
+    struct Blacklist(i64);
+
+    impl Middleware for Blacklist {
+        fn before_request<'a>(
+            &'a self,
+            request: &'a mut ApiRequest,
+        ) -> BoxFuture<'a, TelegrapherResult<()>> {
+            Box::pin(async move {
+                if request.params["chat_id"] == self.0 {
+                    return Err(TelegrapherError::Other("chat is blacklisted".to_string()));
+                }
+                Ok(())
+            })
+        }
+    }
+
+    #[derive(Default)]
+    struct AuditLog(Mutex<Vec<(String, Option<StatusCode>)>>);
+
+    impl Middleware for Arc<AuditLog> {
+        fn after_response<'a>(
+            &'a self,
+            request: &'a ApiRequest,
+            response: &'a ApiResponse<'a>,
+        ) -> BoxFuture<'a, ()> {
+            self.0
+                .lock()
+                .unwrap()
+                .push((request.method.clone(), response.status));
+            Box::pin(async {})
+        }
+    }
+
+    #[tokio::test]
+    async fn test_middleware() {
+        let audit_log = Arc::new(AuditLog::default());
+        let bot = Bot::new("123:abc", 1)
+            .with_api_url(&start_api_server().await)
+            .with_middleware(DefaultParseMode)
+            .with_middleware(Blacklist(666))
+            .with_middleware(audit_log.clone());
+
+        let params = SendMessageParamsBuilder::default()
+            .chat_id(1)
+            .text("hello")
+            .build()
+            .unwrap();
+        let message: Message = bot.send_message_throttled(&params).await.unwrap();
+        assert!(message.text.unwrap().contains(r#""parse_mode":"HTML""#));
+
+        let params = SendMessageParamsBuilder::default()
+            .chat_id(666)
+            .text("hello")
+            .build()
+            .unwrap();
+        assert!(bot.send_message_throttled(&params).await.is_err());
+
+        let audit_log = audit_log.0.lock().unwrap();
+        assert_eq!(
+            *audit_log,
+            vec![("sendMessage".to_string(), Some(StatusCode::OK))]
+        );
+    }
+}
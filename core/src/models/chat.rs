@@ -1,6 +1,11 @@
 use serde::{Deserialize, Serialize};
 
-use crate::models::user::User;
+use crate::models::{
+    business::{BusinessIntro, BusinessLocation, BusinessOpeningHours},
+    message::Message,
+    reaction::ReactionType,
+    user::User,
+};
 
 use super::location::Location;
 
@@ -22,6 +27,71 @@ pub struct Chat {
     pub is_forum: Option<bool>,
 }
 
+/// Full information about a chat, returned by `getChat`.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct ChatFullInfo {
+    pub id: i64,
+    /// Type of the chat, can be either “private”, “group”, “supergroup” or “channel”
+    #[serde(rename = "type")]
+    pub chat_type: String,
+    pub title: Option<String>,
+    pub username: Option<String>,
+    pub first_name: Option<String>,
+    pub last_name: Option<String>,
+    pub is_forum: Option<bool>,
+    /// Identifier of the accent color for the chat name and backgrounds
+    pub accent_color_id: u8,
+    /// Maximum number of reactions that can be set on a message in the chat
+    pub max_reaction_count: u64,
+    pub photo: Option<ChatPhoto>,
+    pub active_usernames: Option<Vec<String>>,
+    /// For private chats, the date of birth of the user
+    pub birthdate: Option<Birthdate>,
+    pub business_intro: Option<BusinessIntro>,
+    pub business_location: Option<BusinessLocation>,
+    pub business_opening_hours: Option<BusinessOpeningHours>,
+    /// For private chats, the personal channel of the user
+    pub personal_chat: Option<Box<Chat>>,
+    /// Allowed reactions, all emoji reactions are allowed if omitted
+    pub available_reactions: Option<Vec<ReactionType>>,
+    pub background_custom_emoji_id: Option<String>,
+    pub profile_accent_color_id: Option<u8>,
+    pub profile_background_custom_emoji_id: Option<String>,
+    pub emoji_status_custom_emoji_id: Option<String>,
+    pub emoji_status_expiration_date: Option<u64>,
+    /// Bio of the other party in a private chat
+    pub bio: Option<String>,
+    pub has_private_forwards: Option<bool>,
+    pub has_restricted_voice_and_video_messages: Option<bool>,
+    pub join_to_send_messages: Option<bool>,
+    pub join_by_request: Option<bool>,
+    /// Description, for groups, supergroups and channel chats
+    pub description: Option<String>,
+    /// Primary invite link, for groups, supergroups and channel chats
+    pub invite_link: Option<String>,
+    /// The most recent pinned message
+    pub pinned_message: Option<Box<Message>>,
+    /// Default chat member permissions, for groups and supergroups
+    pub permissions: Option<ChatPermissions>,
+    pub can_send_paid_media: Option<bool>,
+    /// Minimum delay in seconds between two messages sent by an unprivileged user
+    pub slow_mode_delay: Option<u64>,
+    pub unrestrict_boost_count: Option<u64>,
+    /// Time in seconds after which all messages sent to the chat will be deleted
+    pub message_auto_delete_time: Option<u64>,
+    pub has_aggressive_anti_spam_enabled: Option<bool>,
+    pub has_hidden_members: Option<bool>,
+    pub has_protected_content: Option<bool>,
+    pub has_visible_history: Option<bool>,
+    pub sticker_set_name: Option<String>,
+    pub can_set_sticker_set: Option<bool>,
+    pub custom_emoji_sticker_set_name: Option<String>,
+    /// The discussion group of a channel, or the channel of a discussion group
+    pub linked_chat_id: Option<i64>,
+    /// For supergroups, the location to which the supergroup is connected
+    pub location: Option<ChatLocation>,
+}
+
 #[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
 pub struct ChatAdministratorRights {
     pub is_anonymous: bool,
@@ -70,23 +140,41 @@ pub struct ChatInviteLink {
     pub expire_date: Option<u64>,
     pub member_limit: Option<u64>,
     pub pending_join_request_count: Option<u64>,
+    /// Seconds the subscription is active for before the next payment
+    pub subscription_period: Option<u64>,
+    /// Telegram Stars a member pays initially and after each subscription period
+    pub subscription_price: Option<u64>,
 }
 
-#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize, PartialEq, Eq)]
 pub struct ChatPermissions {
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub can_send_messages: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub can_send_audios: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub can_send_documents: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub can_send_photos: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub can_send_videos: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub can_send_video_notes: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub can_send_voice_notes: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub can_send_polls: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub can_send_other_messages: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub can_add_web_page_previews: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub can_change_info: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub can_invite_users: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub can_pin_messages: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub can_manage_topics: Option<bool>,
 }
 
@@ -102,3 +190,34 @@ pub struct Birthdate {
     pub month: u8,
     pub year: u16,
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_chat_full_info() {
+        let json = r#"{
+            "id": -1001234,
+            "type": "supergroup",
+            "title": "Rust",
+            "accent_color_id": 3,
+            "max_reaction_count": 11,
+            "description": "Rust chat",
+            "permissions": {"can_send_messages": true, "can_send_photos": false},
+            "slow_mode_delay": 30,
+            "linked_chat_id": -1005678,
+            "pinned_message": {
+                "message_id": 7,
+                "date": 1700000000,
+                "chat": {"id": -1001234, "type": "supergroup", "title": "Rust"},
+                "text": "rules"
+            }
+        }"#;
+        let chat: ChatFullInfo = serde_json::from_str(json).unwrap();
+        assert_eq!(chat.slow_mode_delay, Some(30));
+        assert_eq!(chat.linked_chat_id, Some(-1005678));
+        assert_eq!(chat.permissions.unwrap().can_send_photos, Some(false));
+        assert_eq!(chat.pinned_message.unwrap().text.as_deref(), Some("rules"));
+    }
+}
@@ -7,6 +7,7 @@ use crate::models::user::User;
 
 use super::link_preview::LinkPreviewOptions;
 use super::reply_markup::InlineKeyboardMarkup;
+use super::web_app::WebAppInfo;
 
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
 pub struct LabeledPrice {
@@ -18,7 +19,9 @@ pub struct LabeledPrice {
 pub struct InlineQuery {
     pub id: String,
     pub from: User,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub location: Option<Location>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub chat_type: Option<String>,
     pub query: String,
     pub offset: String,
@@ -29,12 +32,19 @@ pub struct InlineQueryResultArticle {
     pub id: String,
     pub title: String,
     pub input_message_content: InputMessageContent,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub reply_markup: Option<InlineKeyboardMarkup>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub url: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub hide_url: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub description: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub thumbnail_url: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub thumbnail_width: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub thumbnail_height: Option<u64>,
 }
 
@@ -43,14 +53,23 @@ pub struct InlineQueryResultPhoto {
     pub id: String,
     pub photo_url: String,
     pub thumbnail_url: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub photo_width: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub photo_height: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub title: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub description: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub caption: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub parse_mode: Option<ParseMode>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub caption_entities: Option<Vec<MessageEntity>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub reply_markup: Option<InlineKeyboardMarkup>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub input_message_content: Option<InputMessageContent>,
 }
 
@@ -58,16 +77,26 @@ pub struct InlineQueryResultPhoto {
 pub struct InlineQueryResultGif {
     pub id: String,
     pub gif_url: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub gif_width: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub gif_height: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub gif_duration: Option<u64>,
     pub thumbnail_url: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub thumbnail_mime_type: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub title: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub caption: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub parse_mode: Option<ParseMode>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub caption_entities: Option<Vec<MessageEntity>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub reply_markup: Option<InlineKeyboardMarkup>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub input_message_content: Option<InputMessageContent>,
 }
 
@@ -75,16 +104,26 @@ pub struct InlineQueryResultGif {
 pub struct InlineQueryResultMpeg4Gif {
     pub id: String,
     pub mpeg4_url: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub mpeg4_width: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub mpeg4_height: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub mpeg4_duration: Option<u64>,
     pub thumbnail_url: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub thumbnail_mime_type: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub title: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub caption: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub parse_mode: Option<ParseMode>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub caption_entities: Option<Vec<MessageEntity>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub reply_markup: Option<InlineKeyboardMarkup>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub input_message_content: Option<InputMessageContent>,
 }
 
@@ -95,14 +134,23 @@ pub struct InlineQueryResultVideo {
     pub mime_type: String,
     pub thumbnail_url: String,
     pub title: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub caption: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub parse_mode: Option<ParseMode>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub caption_entities: Option<Vec<MessageEntity>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub video_width: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub video_height: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub video_duration: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub description: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub reply_markup: Option<InlineKeyboardMarkup>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub input_message_content: Option<InputMessageContent>,
 }
 
@@ -111,12 +159,19 @@ pub struct InlineQueryResultAudio {
     pub id: String,
     pub audio_url: String,
     pub title: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub caption: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub parse_mode: Option<ParseMode>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub caption_entities: Option<Vec<MessageEntity>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub performer: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub audio_duration: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub reply_markup: Option<InlineKeyboardMarkup>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub input_message_content: Option<InputMessageContent>,
 }
 
@@ -125,11 +180,17 @@ pub struct InlineQueryResultVoice {
     pub id: String,
     pub voice_url: String,
     pub title: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub caption: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub parse_mode: Option<ParseMode>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub caption_entities: Option<Vec<MessageEntity>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub voice_duration: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub reply_markup: Option<InlineKeyboardMarkup>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub input_message_content: Option<InputMessageContent>,
 }
 
@@ -137,16 +198,25 @@ pub struct InlineQueryResultVoice {
 pub struct InlineQueryResultDocument {
     pub id: String,
     pub title: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub caption: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub parse_mode: Option<ParseMode>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub caption_entities: Option<Vec<MessageEntity>>,
     pub document_url: String,
     pub mime_type: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub description: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub reply_markup: Option<InlineKeyboardMarkup>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub input_message_content: Option<InputMessageContent>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub thumbnail_url: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub thumbnail_width: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub thumbnail_height: Option<u64>,
 }
 
@@ -156,14 +226,23 @@ pub struct InlineQueryResultLocation {
     pub latitude: f64,
     pub longitude: f64,
     pub title: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub horizontal_accuracy: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub live_period: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub heading: Option<u16>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub proximity_alert_radius: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub reply_markup: Option<InlineKeyboardMarkup>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub input_message_content: Option<InputMessageContent>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub thumbnail_url: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub thumbnail_width: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub thumbnail_height: Option<u64>,
 }
 
@@ -174,14 +253,23 @@ pub struct InlineQueryResultVenue {
     pub longitude: f64,
     pub title: String,
     pub address: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub foursquare_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub foursquare_type: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub google_place_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub google_place_type: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub reply_markup: Option<InlineKeyboardMarkup>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub input_message_content: Option<InputMessageContent>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub thumbnail_url: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub thumbnail_width: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub thumbnail_height: Option<u64>,
 }
 
@@ -190,12 +278,19 @@ pub struct InlineQueryResultContact {
     pub id: String,
     pub phone_number: String,
     pub first_name: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub last_name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub vcard: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub reply_markup: Option<InlineKeyboardMarkup>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub input_message_content: Option<InputMessageContent>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub thumbnail_url: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub thumbnail_width: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub thumbnail_height: Option<u64>,
 }
 
@@ -203,6 +298,7 @@ pub struct InlineQueryResultContact {
 pub struct InlineQueryResultGame {
     pub id: String,
     pub game_short_name: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub reply_markup: Option<InlineKeyboardMarkup>,
 }
 
@@ -210,12 +306,19 @@ pub struct InlineQueryResultGame {
 pub struct InlineQueryResultCachedPhoto {
     pub id: String,
     pub photo_file_id: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub title: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub description: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub caption: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub parse_mode: Option<ParseMode>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub caption_entities: Option<Vec<MessageEntity>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub reply_markup: Option<InlineKeyboardMarkup>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub input_message_content: Option<InputMessageContent>,
 }
 
@@ -223,11 +326,17 @@ pub struct InlineQueryResultCachedPhoto {
 pub struct InlineQueryResultCachedGif {
     pub id: String,
     pub gif_file_id: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub title: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub caption: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub parse_mode: Option<ParseMode>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub caption_entities: Option<Vec<MessageEntity>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub reply_markup: Option<InlineKeyboardMarkup>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub input_message_content: Option<InputMessageContent>,
 }
 
@@ -235,11 +344,17 @@ pub struct InlineQueryResultCachedGif {
 pub struct InlineQueryResultCachedMpeg4Gif {
     pub id: String,
     pub mpeg4_file_id: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub title: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub caption: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub parse_mode: Option<ParseMode>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub caption_entities: Option<Vec<MessageEntity>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub reply_markup: Option<InlineKeyboardMarkup>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub input_message_content: Option<InputMessageContent>,
 }
 
@@ -247,7 +362,9 @@ pub struct InlineQueryResultCachedMpeg4Gif {
 pub struct InlineQueryResultCachedSticker {
     pub id: String,
     pub sticker_file_id: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub reply_markup: Option<InlineKeyboardMarkup>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub input_message_content: Option<InputMessageContent>,
 }
 
@@ -256,11 +373,17 @@ pub struct InlineQueryResultCachedDocument {
     pub id: String,
     pub title: String,
     pub document_file_id: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub description: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub caption: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub parse_mode: Option<ParseMode>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub caption_entities: Option<Vec<MessageEntity>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub reply_markup: Option<InlineKeyboardMarkup>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub input_message_content: Option<InputMessageContent>,
 }
 
@@ -269,11 +392,17 @@ pub struct InlineQueryResultCachedVideo {
     pub id: String,
     pub video_file_id: String,
     pub title: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub description: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub caption: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub parse_mode: Option<ParseMode>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub caption_entities: Option<Vec<MessageEntity>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub reply_markup: Option<InlineKeyboardMarkup>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub input_message_content: Option<InputMessageContent>,
 }
 
@@ -282,10 +411,15 @@ pub struct InlineQueryResultCachedVoice {
     pub id: String,
     pub voice_file_id: String,
     pub title: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub caption: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub parse_mode: Option<ParseMode>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub caption_entities: Option<Vec<MessageEntity>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub reply_markup: Option<InlineKeyboardMarkup>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub input_message_content: Option<InputMessageContent>,
 }
 
@@ -293,18 +427,233 @@ pub struct InlineQueryResultCachedVoice {
 pub struct InlineQueryResultCachedAudio {
     pub id: String,
     pub audio_file_id: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub caption: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub parse_mode: Option<ParseMode>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub caption_entities: Option<Vec<MessageEntity>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub reply_markup: Option<InlineKeyboardMarkup>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub input_message_content: Option<InputMessageContent>,
 }
 
+/// One result of an inline query, serialized with its `type` tag.
+/// Cached results share the tag of the result type, telegram tells them apart by the `*_file_id` field.
+#[derive(Debug, Clone, Serialize, PartialEq)]
+#[serde(tag = "type")]
+pub enum InlineQueryResult {
+    #[serde(rename = "article")]
+    Article(InlineQueryResultArticle),
+    #[serde(rename = "photo")]
+    Photo(InlineQueryResultPhoto),
+    #[serde(rename = "gif")]
+    Gif(InlineQueryResultGif),
+    #[serde(rename = "mpeg4_gif")]
+    Mpeg4Gif(InlineQueryResultMpeg4Gif),
+    #[serde(rename = "video")]
+    Video(InlineQueryResultVideo),
+    #[serde(rename = "audio")]
+    Audio(InlineQueryResultAudio),
+    #[serde(rename = "voice")]
+    Voice(InlineQueryResultVoice),
+    #[serde(rename = "document")]
+    Document(InlineQueryResultDocument),
+    #[serde(rename = "location")]
+    Location(InlineQueryResultLocation),
+    #[serde(rename = "venue")]
+    Venue(InlineQueryResultVenue),
+    #[serde(rename = "contact")]
+    Contact(InlineQueryResultContact),
+    #[serde(rename = "game")]
+    Game(InlineQueryResultGame),
+    #[serde(rename = "photo")]
+    CachedPhoto(InlineQueryResultCachedPhoto),
+    #[serde(rename = "gif")]
+    CachedGif(InlineQueryResultCachedGif),
+    #[serde(rename = "mpeg4_gif")]
+    CachedMpeg4Gif(InlineQueryResultCachedMpeg4Gif),
+    #[serde(rename = "sticker")]
+    CachedSticker(InlineQueryResultCachedSticker),
+    #[serde(rename = "document")]
+    CachedDocument(InlineQueryResultCachedDocument),
+    #[serde(rename = "video")]
+    CachedVideo(InlineQueryResultCachedVideo),
+    #[serde(rename = "voice")]
+    CachedVoice(InlineQueryResultCachedVoice),
+    #[serde(rename = "audio")]
+    CachedAudio(InlineQueryResultCachedAudio),
+}
+
+impl From<InlineQueryResultArticle> for InlineQueryResult {
+    fn from(result: InlineQueryResultArticle) -> Self {
+        InlineQueryResult::Article(result)
+    }
+}
+
+impl From<InlineQueryResultPhoto> for InlineQueryResult {
+    fn from(result: InlineQueryResultPhoto) -> Self {
+        InlineQueryResult::Photo(result)
+    }
+}
+
+impl From<InlineQueryResultGif> for InlineQueryResult {
+    fn from(result: InlineQueryResultGif) -> Self {
+        InlineQueryResult::Gif(result)
+    }
+}
+
+impl From<InlineQueryResultMpeg4Gif> for InlineQueryResult {
+    fn from(result: InlineQueryResultMpeg4Gif) -> Self {
+        InlineQueryResult::Mpeg4Gif(result)
+    }
+}
+
+impl From<InlineQueryResultVideo> for InlineQueryResult {
+    fn from(result: InlineQueryResultVideo) -> Self {
+        InlineQueryResult::Video(result)
+    }
+}
+
+impl From<InlineQueryResultAudio> for InlineQueryResult {
+    fn from(result: InlineQueryResultAudio) -> Self {
+        InlineQueryResult::Audio(result)
+    }
+}
+
+impl From<InlineQueryResultVoice> for InlineQueryResult {
+    fn from(result: InlineQueryResultVoice) -> Self {
+        InlineQueryResult::Voice(result)
+    }
+}
+
+impl From<InlineQueryResultDocument> for InlineQueryResult {
+    fn from(result: InlineQueryResultDocument) -> Self {
+        InlineQueryResult::Document(result)
+    }
+}
+
+impl From<InlineQueryResultLocation> for InlineQueryResult {
+    fn from(result: InlineQueryResultLocation) -> Self {
+        InlineQueryResult::Location(result)
+    }
+}
+
+impl From<InlineQueryResultVenue> for InlineQueryResult {
+    fn from(result: InlineQueryResultVenue) -> Self {
+        InlineQueryResult::Venue(result)
+    }
+}
+
+impl From<InlineQueryResultContact> for InlineQueryResult {
+    fn from(result: InlineQueryResultContact) -> Self {
+        InlineQueryResult::Contact(result)
+    }
+}
+
+impl From<InlineQueryResultGame> for InlineQueryResult {
+    fn from(result: InlineQueryResultGame) -> Self {
+        InlineQueryResult::Game(result)
+    }
+}
+
+impl From<InlineQueryResultCachedPhoto> for InlineQueryResult {
+    fn from(result: InlineQueryResultCachedPhoto) -> Self {
+        InlineQueryResult::CachedPhoto(result)
+    }
+}
+
+impl From<InlineQueryResultCachedGif> for InlineQueryResult {
+    fn from(result: InlineQueryResultCachedGif) -> Self {
+        InlineQueryResult::CachedGif(result)
+    }
+}
+
+impl From<InlineQueryResultCachedMpeg4Gif> for InlineQueryResult {
+    fn from(result: InlineQueryResultCachedMpeg4Gif) -> Self {
+        InlineQueryResult::CachedMpeg4Gif(result)
+    }
+}
+
+impl From<InlineQueryResultCachedSticker> for InlineQueryResult {
+    fn from(result: InlineQueryResultCachedSticker) -> Self {
+        InlineQueryResult::CachedSticker(result)
+    }
+}
+
+impl From<InlineQueryResultCachedDocument> for InlineQueryResult {
+    fn from(result: InlineQueryResultCachedDocument) -> Self {
+        InlineQueryResult::CachedDocument(result)
+    }
+}
+
+impl From<InlineQueryResultCachedVideo> for InlineQueryResult {
+    fn from(result: InlineQueryResultCachedVideo) -> Self {
+        InlineQueryResult::CachedVideo(result)
+    }
+}
+
+impl From<InlineQueryResultCachedVoice> for InlineQueryResult {
+    fn from(result: InlineQueryResultCachedVoice) -> Self {
+        InlineQueryResult::CachedVoice(result)
+    }
+}
+
+impl From<InlineQueryResultCachedAudio> for InlineQueryResult {
+    fn from(result: InlineQueryResultCachedAudio) -> Self {
+        InlineQueryResult::CachedAudio(result)
+    }
+}
+
+impl InlineQueryResult {
+    /// Unique identifier of the result, 1-64 bytes.
+    pub fn id(&self) -> &str {
+        match self {
+            InlineQueryResult::Article(result) => &result.id,
+            InlineQueryResult::Photo(result) => &result.id,
+            InlineQueryResult::Gif(result) => &result.id,
+            InlineQueryResult::Mpeg4Gif(result) => &result.id,
+            InlineQueryResult::Video(result) => &result.id,
+            InlineQueryResult::Audio(result) => &result.id,
+            InlineQueryResult::Voice(result) => &result.id,
+            InlineQueryResult::Document(result) => &result.id,
+            InlineQueryResult::Location(result) => &result.id,
+            InlineQueryResult::Venue(result) => &result.id,
+            InlineQueryResult::Contact(result) => &result.id,
+            InlineQueryResult::Game(result) => &result.id,
+            InlineQueryResult::CachedPhoto(result) => &result.id,
+            InlineQueryResult::CachedGif(result) => &result.id,
+            InlineQueryResult::CachedMpeg4Gif(result) => &result.id,
+            InlineQueryResult::CachedSticker(result) => &result.id,
+            InlineQueryResult::CachedDocument(result) => &result.id,
+            InlineQueryResult::CachedVideo(result) => &result.id,
+            InlineQueryResult::CachedVoice(result) => &result.id,
+            InlineQueryResult::CachedAudio(result) => &result.id,
+        }
+    }
+}
+
+/// A button shown above the inline query results.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct InlineQueryResultsButton {
+    pub text: String,
+    /// Web App launched when the user presses the button
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub web_app: Option<WebAppInfo>,
+    /// Deep-linking parameter for the /start message sent to the bot when the user presses the button
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub start_parameter: Option<String>,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
 pub struct InputTextMessageContent {
     pub message_text: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub parse_mode: Option<ParseMode>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub entities: Option<Vec<MessageEntity>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub link_preview_options: Option<LinkPreviewOptions>,
 }
 
@@ -312,9 +661,13 @@ pub struct InputTextMessageContent {
 pub struct InputLocationMessageContent {
     pub latitude: f64,
     pub longitude: f64,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub horizontal_accuracy: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub live_period: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub heading: Option<u16>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub proximity_alert_radius: Option<u64>,
 }
 
@@ -324,9 +677,13 @@ pub struct InputVenueMessageContent {
     pub longitude: f64,
     pub title: String,
     pub address: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub foursquare_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub foursquare_type: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub google_place_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub google_place_type: Option<String>,
 }
 
@@ -334,7 +691,9 @@ pub struct InputVenueMessageContent {
 pub struct InputContactMessageContent {
     pub phone_number: String,
     pub first_name: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub last_name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub vcard: Option<String>,
 }
 
@@ -343,22 +702,38 @@ pub struct InputInvoiceMessageContent {
     pub title: String,
     pub description: String,
     pub payload: String,
-    pub provider_token: String,
+    /// Omitted for payments in Telegram Stars
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub provider_token: Option<String>,
     pub currency: String,
     pub prices: Vec<LabeledPrice>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub max_tip_amount: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub suggested_tip_amounts: Option<Vec<u64>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub provider_data: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub photo_url: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub photo_size: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub photo_width: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub photo_height: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub need_name: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub need_phone_number: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub need_email: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub need_shipping_address: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub send_phone_number_to_provider: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub send_email_to_provider: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub is_flexible: Option<bool>,
 }
 
@@ -371,3 +746,23 @@ pub enum InputMessageContent {
     Contact(InputContactMessageContent),
     Invoice(InputInvoiceMessageContent),
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_inline_query_result_type() {
+        let result = InlineQueryResult::from(InlineQueryResultCachedSticker {
+            id: "1".to_string(),
+            sticker_file_id: "CAACAgIAAxkBAAI".to_string(),
+            reply_markup: None,
+            input_message_content: None,
+        });
+        assert_eq!(
+            serde_json::to_value(&result).unwrap(),
+            serde_json::json!({"type": "sticker", "id": "1", "sticker_file_id": "CAACAgIAAxkBAAI"})
+        );
+        assert_eq!(result.id(), "1");
+    }
+}
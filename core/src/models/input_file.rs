@@ -0,0 +1,311 @@
+use std::fmt::Debug;
+use std::path::PathBuf;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, Mutex};
+
+use bytes::Bytes;
+use reqwest::multipart::Part;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use tokio::io::AsyncRead;
+use tokio_util::io::ReaderStream;
+
+use crate::TelegrapherError;
+
+/// A file field of a method: a new file to upload, or a file_id / HTTP url of an existing file.
+#[derive(Clone, Debug, Serialize, PartialEq, Eq)]
+#[serde(untagged)]
+pub enum FileUpload {
+    InputFile(InputFile),
+    String(String),
+}
+
+impl FileUpload {
+    /// A file already stored on the telegram servers.
+    pub fn file_id(file_id: impl Into<String>) -> Self {
+        FileUpload::String(file_id.into())
+    }
+
+    /// A file telegram downloads from the internet.
+    pub fn url(url: impl Into<String>) -> Self {
+        FileUpload::String(url.into())
+    }
+}
+
+impl FileUpload {
+    /// The file to upload, `None` for a file_id or url.
+    pub fn input_file(&self) -> Option<&InputFile> {
+        match self {
+            FileUpload::InputFile(input_file) => Some(input_file),
+            FileUpload::String(_) => None,
+        }
+    }
+}
+
+/// A deserialized string is always a file_id or url, never a local file:
+/// the file of a serialized `InputFile` is only referenced as `attach://<attach_name>`.
+impl<'de> Deserialize<'de> for FileUpload {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        String::deserialize(deserializer).map(FileUpload::String)
+    }
+}
+
+impl From<InputFile> for FileUpload {
+    fn from(input_file: InputFile) -> Self {
+        FileUpload::InputFile(input_file)
+    }
+}
+
+/// Params with fields holding new files. The files are uploaded as parts of one multipart request,
+/// the fields themselves reference them with `attach://<attach_name>`.
+pub trait InputFiles {
+    fn input_files(&self) -> Vec<&InputFile>;
+}
+
+impl InputFiles for FileUpload {
+    fn input_files(&self) -> Vec<&InputFile> {
+        self.input_file().into_iter().collect()
+    }
+}
+
+impl InputFiles for InputFile {
+    fn input_files(&self) -> Vec<&InputFile> {
+        vec![self]
+    }
+}
+
+impl<T: InputFiles> InputFiles for Option<T> {
+    fn input_files(&self) -> Vec<&InputFile> {
+        self.as_ref().map(T::input_files).unwrap_or_default()
+    }
+}
+
+impl<T: InputFiles> InputFiles for Vec<T> {
+    fn input_files(&self) -> Vec<&InputFile> {
+        self.iter().flat_map(T::input_files).collect()
+    }
+}
+
+/// Every input file gets its own part name, so several files fit into one request.
+static NEXT_ATTACH_ID: AtomicU64 = AtomicU64::new(0);
+
+fn next_attach_name() -> String {
+    format!("file{}", NEXT_ATTACH_ID.fetch_add(1, Ordering::Relaxed))
+}
+
+type SharedReader = Arc<Mutex<Option<Box<dyn AsyncRead + Send + Sync + Unpin>>>>;
+
+/// A new file uploaded with multipart/form-data. The content is streamed, it is never read into memory as a whole.
+#[derive(Clone)]
+pub struct InputFile {
+    file_name: String,
+    attach_name: String,
+    source: InputFileSource,
+}
+
+#[derive(Clone)]
+enum InputFileSource {
+    Path(PathBuf),
+    Memory(Bytes),
+    Reader {
+        reader: SharedReader,
+        length: Option<u64>,
+    },
+}
+
+impl InputFile {
+    /// A file on the local filesystem, the file name is taken from the path.
+    pub fn path(path: impl Into<PathBuf>) -> Self {
+        let path = path.into();
+        let file_name = path
+            .file_name()
+            .and_then(|name| name.to_str())
+            .unwrap_or("file")
+            .to_string();
+        Self {
+            file_name,
+            attach_name: next_attach_name(),
+            source: InputFileSource::Path(path),
+        }
+    }
+
+    /// File content in memory, e.g. a generated image or csv export.
+    pub fn memory(file_name: impl Into<String>, data: impl Into<Bytes>) -> Self {
+        Self {
+            file_name: file_name.into(),
+            attach_name: next_attach_name(),
+            source: InputFileSource::Memory(data.into()),
+        }
+    }
+
+    /// File content read from an async reader. Pass the length if it is known, otherwise the body is sent chunked.
+    /// A reader can be uploaded only once, so requests with it are never retried.
+    pub fn reader(
+        file_name: impl Into<String>,
+        reader: impl AsyncRead + Send + Sync + Unpin + 'static,
+        length: Option<u64>,
+    ) -> Self {
+        Self {
+            file_name: file_name.into(),
+            attach_name: next_attach_name(),
+            source: InputFileSource::Reader {
+                reader: Arc::new(Mutex::new(Some(Box::new(reader)))),
+                length,
+            },
+        }
+    }
+
+    /// Change the file name telegram sees.
+    pub fn with_file_name(mut self, file_name: impl Into<String>) -> Self {
+        self.file_name = file_name.into();
+        self
+    }
+
+    pub fn file_name(&self) -> &str {
+        &self.file_name
+    }
+
+    /// Name of the multipart part carrying the content.
+    pub fn attach_name(&self) -> &str {
+        &self.attach_name
+    }
+
+    /// false if the content can be read only once.
+    pub fn is_replayable(&self) -> bool {
+        !matches!(self.source, InputFileSource::Reader { .. })
+    }
+
+    /// Build the multipart part streaming the file content.
+    pub(crate) async fn to_part(&self) -> Result<Part, TelegrapherError> {
+        let part = match &self.source {
+            InputFileSource::Path(path) => {
+                let file = tokio::fs::File::open(path).await?;
+                let length = file.metadata().await?.len();
+                let stream = ReaderStream::new(file);
+                Part::stream_with_length(reqwest::Body::wrap_stream(stream), length)
+            }
+            InputFileSource::Memory(data) => {
+                Part::stream_with_length(reqwest::Body::from(data.clone()), data.len() as u64)
+            }
+            InputFileSource::Reader { reader, length } => {
+                let reader = reader
+                    .lock()
+                    .map_err(|_| TelegrapherError::Other("input file lock poisoned".to_string()))?
+                    .take()
+                    .ok_or_else(|| {
+                        TelegrapherError::InvalidParams(format!(
+                            "the reader of {} has already been uploaded",
+                            self.file_name
+                        ))
+                    })?;
+                let body = reqwest::Body::wrap_stream(ReaderStream::new(reader));
+                match length {
+                    Some(length) => Part::stream_with_length(body, *length),
+                    None => Part::stream(body),
+                }
+            }
+        };
+        Ok(part.file_name(self.file_name.clone()))
+    }
+}
+
+impl Debug for InputFile {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let source = match &self.source {
+            InputFileSource::Path(path) => format!("Path({:?})", path),
+            InputFileSource::Memory(data) => format!("Memory({} bytes)", data.len()),
+            InputFileSource::Reader { length, .. } => format!("Reader(length: {:?})", length),
+        };
+        f.debug_struct("InputFile")
+            .field("file_name", &self.file_name)
+            .field("attach_name", &self.attach_name)
+            .field("source", &source)
+            .finish()
+    }
+}
+
+impl PartialEq for InputFile {
+    fn eq(&self, other: &Self) -> bool {
+        let same_source = match (&self.source, &other.source) {
+            (InputFileSource::Path(a), InputFileSource::Path(b)) => a == b,
+            (InputFileSource::Memory(a), InputFileSource::Memory(b)) => a == b,
+            (
+                InputFileSource::Reader { reader: a, .. },
+                InputFileSource::Reader { reader: b, .. },
+            ) => Arc::ptr_eq(a, b),
+            _ => false,
+        };
+        same_source && self.file_name == other.file_name
+    }
+}
+
+impl Eq for InputFile {}
+
+/// The file content is sent as a multipart part, the json field only references it.
+impl Serialize for InputFile {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&format!("attach://{}", self.attach_name))
+    }
+}
+
+/// A string is read as a path on the local filesystem.
+impl<'de> Deserialize<'de> for InputFile {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let path = PathBuf::deserialize(deserializer)?;
+        Ok(InputFile::path(path))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_reader_is_uploaded_once() {
+        let input_file = InputFile::reader("report.csv", std::io::Cursor::new(b"a,b\n1,2\n"), None);
+        assert!(!input_file.is_replayable());
+        assert!(input_file.to_part().await.is_ok());
+        assert!(input_file.clone().to_part().await.is_err());
+
+        let input_file = InputFile::memory("report.csv", b"a,b\n1,2\n".to_vec());
+        assert!(input_file.is_replayable());
+        assert!(input_file.to_part().await.is_ok());
+        assert!(input_file.to_part().await.is_ok());
+    }
+
+    #[test]
+    fn test_file_id_round_trip() {
+        let upload = FileUpload::file_id("AgADBAADbqcxG0Y");
+        let json = serde_json::to_string(&upload).unwrap();
+        assert_eq!(json, "\"AgADBAADbqcxG0Y\"");
+        assert_eq!(serde_json::from_str::<FileUpload>(&json).unwrap(), upload);
+
+        let upload = FileUpload::url("https://example.com/photo.jpg");
+        let json = serde_json::to_string(&upload).unwrap();
+        assert_eq!(serde_json::from_str::<FileUpload>(&json).unwrap(), upload);
+    }
+
+    #[test]
+    fn test_file_name() {
+        assert_eq!(
+            InputFile::path("examples/photo.jpg").file_name(),
+            "photo.jpg"
+        );
+    }
+
+    #[test]
+    fn test_attach_names() {
+        let photo = InputFile::path("examples/photo.jpg");
+        let thumbnail = InputFile::memory("thumb.jpg", vec![0u8; 4]);
+        assert_ne!(photo.attach_name(), thumbnail.attach_name());
+        assert_eq!(photo.clone().attach_name(), photo.attach_name());
+
+        let upload = FileUpload::from(photo.clone());
+        assert_eq!(
+            serde_json::to_value(&upload).unwrap(),
+            format!("attach://{}", photo.attach_name())
+        );
+
+        let uploads = vec![upload, FileUpload::file_id("AgAD"), thumbnail.into()];
+        assert_eq!(uploads.input_files().len(), 2);
+    }
+}
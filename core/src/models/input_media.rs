@@ -0,0 +1,271 @@
+use derive_builder::Builder;
+use serde::{Deserialize, Serialize};
+
+use crate::models::{
+    input_file::{FileUpload, InputFile, InputFiles},
+    message_entity::MessageEntity,
+    parse_mode::ParseMode,
+};
+
+/// Content of a media message to be sent, used by `sendMediaGroup` and `editMessageMedia`.
+/// New files are referenced with `attach://<attach_name>` and uploaded in the same request.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(tag = "type", rename_all = "lowercase")]
+pub enum InputMedia {
+    Photo(InputMediaPhoto),
+    Video(InputMediaVideo),
+    Animation(InputMediaAnimation),
+    Audio(InputMediaAudio),
+    Document(InputMediaDocument),
+}
+
+impl InputFiles for InputMedia {
+    fn input_files(&self) -> Vec<&InputFile> {
+        match self {
+            InputMedia::Photo(media) => media.input_files(),
+            InputMedia::Video(media) => media.input_files(),
+            InputMedia::Animation(media) => media.input_files(),
+            InputMedia::Audio(media) => media.input_files(),
+            InputMedia::Document(media) => media.input_files(),
+        }
+    }
+}
+
+impl From<InputMediaPhoto> for InputMedia {
+    fn from(media: InputMediaPhoto) -> Self {
+        InputMedia::Photo(media)
+    }
+}
+
+impl From<InputMediaVideo> for InputMedia {
+    fn from(media: InputMediaVideo) -> Self {
+        InputMedia::Video(media)
+    }
+}
+
+impl From<InputMediaAnimation> for InputMedia {
+    fn from(media: InputMediaAnimation) -> Self {
+        InputMedia::Animation(media)
+    }
+}
+
+impl From<InputMediaAudio> for InputMedia {
+    fn from(media: InputMediaAudio) -> Self {
+        InputMedia::Audio(media)
+    }
+}
+
+impl From<InputMediaDocument> for InputMedia {
+    fn from(media: InputMediaDocument) -> Self {
+        InputMedia::Document(media)
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Builder)]
+pub struct InputMediaPhoto {
+    #[builder(setter(into))]
+    pub media: FileUpload,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[builder(setter(into, strip_option), default)]
+    pub caption: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[builder(setter(into, strip_option), default)]
+    pub parse_mode: Option<ParseMode>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[builder(setter(into, strip_option), default)]
+    pub caption_entities: Option<Vec<MessageEntity>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[builder(setter(into, strip_option), default)]
+    pub show_caption_above_media: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[builder(setter(into, strip_option), default)]
+    pub has_spoiler: Option<bool>,
+}
+
+impl InputFiles for InputMediaPhoto {
+    fn input_files(&self) -> Vec<&InputFile> {
+        self.media.input_files()
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Builder)]
+pub struct InputMediaVideo {
+    #[builder(setter(into))]
+    pub media: FileUpload,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[builder(setter(into, strip_option), default)]
+    pub thumbnail: Option<FileUpload>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[builder(setter(into, strip_option), default)]
+    pub caption: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[builder(setter(into, strip_option), default)]
+    pub parse_mode: Option<ParseMode>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[builder(setter(into, strip_option), default)]
+    pub caption_entities: Option<Vec<MessageEntity>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[builder(setter(into, strip_option), default)]
+    pub show_caption_above_media: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[builder(setter(into, strip_option), default)]
+    pub width: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[builder(setter(into, strip_option), default)]
+    pub height: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[builder(setter(into, strip_option), default)]
+    pub duration: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[builder(setter(into, strip_option), default)]
+    pub supports_streaming: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[builder(setter(into, strip_option), default)]
+    pub has_spoiler: Option<bool>,
+}
+
+impl InputFiles for InputMediaVideo {
+    fn input_files(&self) -> Vec<&InputFile> {
+        let mut files = self.media.input_files();
+        files.extend(self.thumbnail.input_files());
+        files
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Builder)]
+pub struct InputMediaAnimation {
+    #[builder(setter(into))]
+    pub media: FileUpload,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[builder(setter(into, strip_option), default)]
+    pub thumbnail: Option<FileUpload>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[builder(setter(into, strip_option), default)]
+    pub caption: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[builder(setter(into, strip_option), default)]
+    pub parse_mode: Option<ParseMode>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[builder(setter(into, strip_option), default)]
+    pub caption_entities: Option<Vec<MessageEntity>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[builder(setter(into, strip_option), default)]
+    pub show_caption_above_media: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[builder(setter(into, strip_option), default)]
+    pub width: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[builder(setter(into, strip_option), default)]
+    pub height: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[builder(setter(into, strip_option), default)]
+    pub duration: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[builder(setter(into, strip_option), default)]
+    pub has_spoiler: Option<bool>,
+}
+
+impl InputFiles for InputMediaAnimation {
+    fn input_files(&self) -> Vec<&InputFile> {
+        let mut files = self.media.input_files();
+        files.extend(self.thumbnail.input_files());
+        files
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Builder)]
+pub struct InputMediaAudio {
+    #[builder(setter(into))]
+    pub media: FileUpload,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[builder(setter(into, strip_option), default)]
+    pub thumbnail: Option<FileUpload>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[builder(setter(into, strip_option), default)]
+    pub caption: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[builder(setter(into, strip_option), default)]
+    pub parse_mode: Option<ParseMode>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[builder(setter(into, strip_option), default)]
+    pub caption_entities: Option<Vec<MessageEntity>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[builder(setter(into, strip_option), default)]
+    pub duration: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[builder(setter(into, strip_option), default)]
+    pub performer: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[builder(setter(into, strip_option), default)]
+    pub title: Option<String>,
+}
+
+impl InputFiles for InputMediaAudio {
+    fn input_files(&self) -> Vec<&InputFile> {
+        let mut files = self.media.input_files();
+        files.extend(self.thumbnail.input_files());
+        files
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Builder)]
+pub struct InputMediaDocument {
+    #[builder(setter(into))]
+    pub media: FileUpload,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[builder(setter(into, strip_option), default)]
+    pub thumbnail: Option<FileUpload>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[builder(setter(into, strip_option), default)]
+    pub caption: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[builder(setter(into, strip_option), default)]
+    pub parse_mode: Option<ParseMode>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[builder(setter(into, strip_option), default)]
+    pub caption_entities: Option<Vec<MessageEntity>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[builder(setter(into, strip_option), default)]
+    pub disable_content_type_detection: Option<bool>,
+}
+
+impl InputFiles for InputMediaDocument {
+    fn input_files(&self) -> Vec<&InputFile> {
+        let mut files = self.media.input_files();
+        files.extend(self.thumbnail.input_files());
+        files
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_input_media_group() {
+        let photo = InputFile::memory("chart.png", vec![0u8; 4]);
+        let media: Vec<InputMedia> = vec![
+            InputMediaPhotoBuilder::default()
+                .media(photo.clone())
+                .caption("weekly report")
+                .build()
+                .unwrap()
+                .into(),
+            InputMediaDocumentBuilder::default()
+                .media(FileUpload::file_id("BQACAgIAAxkBAAI"))
+                .build()
+                .unwrap()
+                .into(),
+        ];
+        assert_eq!(media.input_files(), vec![&photo]);
+
+        let json = serde_json::to_value(&media).unwrap();
+        assert_eq!(
+            json,
+            serde_json::json!([
+                {"type": "photo", "media": format!("attach://{}", photo.attach_name()), "caption": "weekly report"},
+                {"type": "document", "media": "BQACAgIAAxkBAAI"},
+            ])
+        );
+    }
+}
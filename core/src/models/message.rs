@@ -42,11 +42,32 @@ pub struct MessageId {
 
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 #[serde(untagged)]
+#[allow(clippy::large_enum_variant)]
 pub enum MaybeInaccessibleMessage {
     Message(Message),
     InaccessibleMessage(InaccessibleMessage),
 }
 
+/// Result of editing a message: the edited message, or `true` when the message was sent via inline mode
+/// and edited by `inline_message_id`.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(untagged)]
+#[allow(clippy::large_enum_variant)]
+pub enum EditedMessage {
+    Message(Message),
+    Inline(bool),
+}
+
+impl EditedMessage {
+    /// The edited message, `None` for an inline message.
+    pub fn message(self) -> Option<Message> {
+        match self {
+            EditedMessage::Message(message) => Some(message),
+            EditedMessage::Inline(_) => None,
+        }
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub struct InaccessibleMessage {
     pub chat: Chat,
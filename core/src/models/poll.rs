@@ -2,6 +2,7 @@ use serde::{Deserialize, Serialize};
 
 use crate::models::chat::Chat;
 use crate::models::message_entity::MessageEntity;
+use crate::models::parse_mode::ParseMode;
 use crate::models::user::User;
 
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
@@ -35,3 +36,35 @@ pub struct Poll {
     pub open_period: Option<u64>,
     pub close_date: Option<u64>,
 }
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum PollType {
+    Regular,
+    Quiz,
+}
+
+/// An answer option of a poll to be sent.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq)]
+pub struct InputPollOption {
+    pub text: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub text_parse_mode: Option<ParseMode>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub text_entities: Option<Vec<MessageEntity>>,
+}
+
+impl From<&str> for InputPollOption {
+    fn from(text: &str) -> Self {
+        Self::from(text.to_string())
+    }
+}
+
+impl From<String> for InputPollOption {
+    fn from(text: String) -> Self {
+        Self {
+            text,
+            ..Default::default()
+        }
+    }
+}
@@ -7,7 +7,6 @@ use crate::models::chat::Chat;
 use crate::models::contact::Contact;
 use crate::models::dice::Dice;
 use crate::models::document::Document;
-use crate::models::forum::CallbackGame;
 use crate::models::game::Game;
 use crate::models::giveaway::{Giveaway, GiveawayWinners};
 use crate::models::invoice::Invoice;
@@ -23,7 +22,6 @@ use crate::models::venue::Venue;
 use crate::models::video::Video;
 use crate::models::video_note::VideoNote;
 use crate::models::voice::Voice;
-use crate::models::web_app::WebAppInfo;
 
 #[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq)]
 pub struct TextQuote {
@@ -1,8 +1,8 @@
-use std::path::PathBuf;
-
 use serde::{Deserialize, Serialize};
 
 use crate::models::file::File;
+use crate::models::input_file::InputFiles;
+pub use crate::models::input_file::{FileUpload, InputFile};
 use crate::models::mask_position::MaskPosition;
 use crate::models::photo_size::PhotoSize;
 
@@ -39,10 +39,18 @@ pub struct InputSticker {
     pub sticker: FileUpload,
     pub format: StickerFormat,
     pub emoji_list: Vec<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub mask_position: Option<MaskPosition>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub keywords: Option<Vec<String>>,
 }
 
+impl InputFiles for InputSticker {
+    fn input_files(&self) -> Vec<&InputFile> {
+        self.sticker.input_files()
+    }
+}
+
 #[derive(Clone, Copy, Debug, Serialize, Deserialize, PartialEq, Eq)]
 #[serde(rename_all = "lowercase")]
 pub enum StickerFormat {
@@ -50,15 +58,3 @@ pub enum StickerFormat {
     Animated,
     Video,
 }
-
-#[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq)]
-#[serde(untagged)]
-pub enum FileUpload {
-    InputFile(InputFile),
-    String(String),
-}
-
-#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
-pub struct InputFile {
-    pub path: PathBuf,
-}
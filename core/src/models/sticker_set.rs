@@ -12,6 +12,7 @@ pub struct StickerSet {
 
     #[doc(hidden)]
     #[deprecated(since = "0.19.2", note = "Please use `sticker_type` instead")]
+    #[serde(default)]
     pub contains_masks: bool,
     pub stickers: Vec<Sticker>,
     pub thumbnail: Option<PhotoSize>,
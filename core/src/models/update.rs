@@ -27,6 +27,7 @@ pub struct Update {
 
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 #[serde(rename_all = "snake_case")]
+#[allow(clippy::large_enum_variant)]
 pub enum UpdateContent {
     Message(Message),
     EditedMessage(Message),
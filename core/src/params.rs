@@ -1,6 +1,12 @@
 pub mod callback_query_param;
+pub mod chat_params;
 pub mod command_params;
+pub mod file_params;
+pub mod forum_params;
+pub mod inline_query_params;
 pub mod media_params;
 pub mod message_params;
+pub mod payment_params;
+pub mod sticker_params;
 pub mod updates_params;
 pub mod webhook_param;
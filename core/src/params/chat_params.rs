@@ -0,0 +1,263 @@
+use derive_builder::Builder;
+use serde::Serialize;
+
+use crate::models::{
+    chat::ChatPermissions,
+    input_file::{InputFile, InputFiles},
+};
+
+/// Params of the methods taking only the target chat.
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Builder)]
+#[builder(setter(into), default)]
+pub struct ChatIdParams {
+    pub chat_id: i64,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Builder)]
+#[builder(setter(into), default)]
+pub struct BanChatMemberParams {
+    pub chat_id: i64,
+    pub user_id: u64,
+    /// Unix time when the user will be unbanned, banned forever if it is less than 30 seconds from now or more than 366 days
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub until_date: Option<u64>,
+    /// Delete all messages of the user from the chat
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub revoke_messages: Option<bool>,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Builder)]
+#[builder(setter(into), default)]
+pub struct UnbanChatMemberParams {
+    pub chat_id: i64,
+    pub user_id: u64,
+    /// Do nothing if the user is not banned, otherwise a member is removed from the chat
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub only_if_banned: Option<bool>,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Builder)]
+#[builder(setter(into), default)]
+pub struct RestrictChatMemberParams {
+    pub chat_id: i64,
+    pub user_id: u64,
+    pub permissions: ChatPermissions,
+    /// Apply the permissions independently, otherwise can_send_other_messages and can_add_web_page_previews imply the media permissions
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub use_independent_chat_permissions: Option<bool>,
+    /// Unix time when the restrictions will be lifted, restricted forever if it is less than 30 seconds from now or more than 366 days
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub until_date: Option<u64>,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Builder)]
+#[builder(setter(into), default)]
+pub struct PromoteChatMemberParams {
+    pub chat_id: i64,
+    pub user_id: u64,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub is_anonymous: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub can_manage_chat: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub can_delete_messages: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub can_manage_video_chats: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub can_restrict_members: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub can_promote_members: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub can_change_info: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub can_invite_users: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub can_post_stories: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub can_edit_stories: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub can_delete_stories: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub can_post_messages: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub can_edit_messages: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub can_pin_messages: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub can_manage_topics: Option<bool>,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Builder)]
+#[builder(setter(into), default)]
+pub struct SetChatAdministratorCustomTitleParams {
+    pub chat_id: i64,
+    pub user_id: u64,
+    /// 0-16 characters, emoji are not allowed
+    pub custom_title: String,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Builder)]
+#[builder(setter(into), default)]
+pub struct BanChatSenderChatParams {
+    pub chat_id: i64,
+    pub sender_chat_id: i64,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Builder)]
+#[builder(setter(into), default)]
+pub struct UnbanChatSenderChatParams {
+    pub chat_id: i64,
+    pub sender_chat_id: i64,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Builder)]
+#[builder(setter(into), default)]
+pub struct SetChatPermissionsParams {
+    pub chat_id: i64,
+    pub permissions: ChatPermissions,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub use_independent_chat_permissions: Option<bool>,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Builder)]
+#[builder(setter(into), default)]
+pub struct GetChatMemberParams {
+    pub chat_id: i64,
+    pub user_id: u64,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Builder)]
+#[builder(setter(into), default)]
+pub struct CreateChatInviteLinkParams {
+    pub chat_id: i64,
+    /// Invite link name, 0-32 characters
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    /// Unix time when the link will expire
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub expire_date: Option<u64>,
+    /// Maximum number of users that can be members of the chat at the same time after joining via the link, 1-99999
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub member_limit: Option<u64>,
+    /// Users joining via the link need to be approved by chat administrators, member_limit can't be specified
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub creates_join_request: Option<bool>,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Builder)]
+#[builder(setter(into), default)]
+pub struct EditChatInviteLinkParams {
+    pub chat_id: i64,
+    pub invite_link: String,
+    /// Invite link name, 0-32 characters
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    /// Unix time when the link will expire
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub expire_date: Option<u64>,
+    /// Maximum number of users that can be members of the chat at the same time after joining via the link, 1-99999
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub member_limit: Option<u64>,
+    /// Users joining via the link need to be approved by chat administrators, member_limit can't be specified
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub creates_join_request: Option<bool>,
+}
+
+/// A subscription link of a channel, members pay Telegram Stars to join.
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Builder)]
+#[builder(setter(into), default)]
+pub struct CreateChatSubscriptionInviteLinkParams {
+    pub chat_id: i64,
+    /// Invite link name, 0-32 characters
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    /// Seconds the subscription is active for before the next payment, currently always 2592000 (30 days)
+    pub subscription_period: u64,
+    /// Telegram Stars a user must pay initially and after each subscription period, 1-2500
+    pub subscription_price: u64,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Builder)]
+#[builder(setter(into), default)]
+pub struct EditChatSubscriptionInviteLinkParams {
+    pub chat_id: i64,
+    pub invite_link: String,
+    /// Invite link name, 0-32 characters
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Builder)]
+#[builder(setter(into), default)]
+pub struct RevokeChatInviteLinkParams {
+    pub chat_id: i64,
+    pub invite_link: String,
+}
+
+/// Params of approveChatJoinRequest and declineChatJoinRequest.
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Builder)]
+#[builder(setter(into), default)]
+pub struct ChatJoinRequestParams {
+    pub chat_id: i64,
+    pub user_id: u64,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Builder)]
+#[builder(setter(into), default)]
+pub struct SetChatTitleParams {
+    pub chat_id: i64,
+    /// New chat title, 1-128 characters
+    pub title: String,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Builder)]
+#[builder(setter(into), default)]
+pub struct SetChatDescriptionParams {
+    pub chat_id: i64,
+    /// New chat description, 0-255 characters
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Builder)]
+pub struct SetChatPhotoParams {
+    #[builder(setter(into))]
+    pub chat_id: i64,
+    /// New chat photo, it can only be uploaded as a new file
+    #[builder(setter(into))]
+    pub photo: InputFile,
+}
+
+impl InputFiles for SetChatPhotoParams {
+    fn input_files(&self) -> Vec<&InputFile> {
+        self.photo.input_files()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_restrict_chat_member_params() {
+        let params = RestrictChatMemberParamsBuilder::default()
+            .chat_id(-1001234)
+            .user_id(42u64)
+            .permissions(ChatPermissions {
+                can_send_messages: Some(false),
+                ..Default::default()
+            })
+            .use_independent_chat_permissions(true)
+            .build()
+            .unwrap();
+        assert_eq!(
+            serde_json::to_value(&params).unwrap(),
+            serde_json::json!({
+                "chat_id": -1001234,
+                "user_id": 42,
+                "permissions": {"can_send_messages": false},
+                "use_independent_chat_permissions": true,
+            })
+        );
+    }
+}
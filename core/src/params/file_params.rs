@@ -0,0 +1,70 @@
+use std::{fmt::Debug, sync::Arc, time::Duration};
+
+use derive_builder::Builder;
+use serde::{Deserialize, Serialize};
+
+use crate::TelegrapherError;
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Builder)]
+pub struct GetFileParams {
+    #[builder(setter(into))]
+    pub file_id: String,
+}
+
+/// Progress callback of a download, called with the downloaded bytes and the total size if known.
+pub type DownloadProgress = Arc<dyn Fn(u64, Option<u64>) + Send + Sync>;
+
+/// Timeout of a download without `DownloadOptions::timeout`. Large files, up to 2GB from a local Bot API server,
+/// take much longer than the 17 second timeout of the bot's http client.
+pub const DEFAULT_DOWNLOAD_TIMEOUT: Duration = Duration::from_secs(60 * 60);
+
+/// Options of `Bot::download_file` and friends.
+#[derive(Clone, Default)]
+pub struct DownloadOptions {
+    /// Stop the download with `TelegrapherError::FileTooLarge` once the file exceeds this size.
+    pub max_size: Option<u64>,
+    /// Timeout of the whole download, `DEFAULT_DOWNLOAD_TIMEOUT` if not set.
+    pub timeout: Option<Duration>,
+    pub progress: Option<DownloadProgress>,
+}
+
+impl DownloadOptions {
+    pub fn with_max_size(mut self, max_size: u64) -> Self {
+        self.max_size = Some(max_size);
+        self
+    }
+
+    pub fn with_timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    pub fn with_progress(
+        mut self,
+        progress: impl Fn(u64, Option<u64>) + Send + Sync + 'static,
+    ) -> Self {
+        self.progress = Some(Arc::new(progress));
+        self
+    }
+
+    pub(crate) fn timeout(&self) -> Duration {
+        self.timeout.unwrap_or(DEFAULT_DOWNLOAD_TIMEOUT)
+    }
+
+    pub(crate) fn check_size(&self, size: u64) -> Result<(), TelegrapherError> {
+        match self.max_size {
+            Some(limit) if size > limit => Err(TelegrapherError::FileTooLarge { size, limit }),
+            _ => Ok(()),
+        }
+    }
+}
+
+impl Debug for DownloadOptions {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("DownloadOptions")
+            .field("max_size", &self.max_size)
+            .field("timeout", &self.timeout)
+            .field("progress", &self.progress.is_some())
+            .finish()
+    }
+}
@@ -0,0 +1,68 @@
+use derive_builder::Builder;
+use serde::Serialize;
+
+/// The icon colors a forum topic can be created with.
+pub const FORUM_TOPIC_ICON_COLORS: [u64; 6] =
+    [0x6FB9F0, 0xFFD67E, 0xCB86DB, 0x8EEE98, 0xFF93B2, 0xFB6F5F];
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Builder)]
+#[builder(setter(into), default)]
+pub struct CreateForumTopicParams {
+    pub chat_id: i64,
+    /// Topic name, 1-128 characters
+    pub name: String,
+    /// One of `FORUM_TOPIC_ICON_COLORS`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub icon_color: Option<u64>,
+    /// One of the stickers returned by `get_forum_topic_icon_stickers`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub icon_custom_emoji_id: Option<String>,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Builder)]
+#[builder(setter(into), default)]
+pub struct EditForumTopicParams {
+    pub chat_id: i64,
+    pub message_thread_id: i64,
+    /// New topic name, the current name is kept if not specified
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    /// New icon, pass an empty string to remove the icon
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub icon_custom_emoji_id: Option<String>,
+}
+
+/// Params of the methods acting on a single forum topic.
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Builder)]
+#[builder(setter(into), default)]
+pub struct ForumTopicParams {
+    pub chat_id: i64,
+    pub message_thread_id: i64,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Builder)]
+#[builder(setter(into), default)]
+pub struct EditGeneralForumTopicParams {
+    pub chat_id: i64,
+    /// New topic name, 1-128 characters
+    pub name: String,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_create_forum_topic_params() {
+        let params = CreateForumTopicParamsBuilder::default()
+            .chat_id(-1001234)
+            .name("Releases")
+            .icon_color(FORUM_TOPIC_ICON_COLORS[3])
+            .build()
+            .unwrap();
+        assert_eq!(
+            serde_json::to_value(&params).unwrap(),
+            serde_json::json!({"chat_id": -1001234, "name": "Releases", "icon_color": 0x8EEE98})
+        );
+    }
+}
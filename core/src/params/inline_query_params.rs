@@ -0,0 +1,37 @@
+use derive_builder::Builder;
+use serde::Serialize;
+
+use crate::models::inline_query::{InlineQueryResult, InlineQueryResultsButton};
+
+#[derive(Debug, Clone, PartialEq, Serialize, Builder)]
+pub struct AnswerInlineQueryParams {
+    #[builder(setter(into))]
+    pub inline_query_id: String,
+    /// No more than 50 results per query
+    #[builder(setter(into))]
+    pub results: Vec<InlineQueryResult>,
+    /// Seconds the result may be cached on the server, defaults to 300
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[builder(setter(into, strip_option), default)]
+    pub cache_time: Option<u64>,
+    /// Cache the results only for the user that sent the query
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[builder(setter(into, strip_option), default)]
+    pub is_personal: Option<bool>,
+    /// Offset the client sends in the next query to receive more results, empty if there are no more results
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[builder(setter(into, strip_option), default)]
+    pub next_offset: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[builder(setter(into, strip_option), default)]
+    pub button: Option<InlineQueryResultsButton>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Builder)]
+pub struct AnswerWebAppQueryParams {
+    #[builder(setter(into))]
+    pub web_app_query_id: String,
+    /// The message to be sent
+    #[builder(setter(into))]
+    pub result: InlineQueryResult,
+}
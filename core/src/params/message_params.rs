@@ -4,6 +4,7 @@ use crate::models::{
     link_preview::LinkPreviewOptions,
     message_entity::MessageEntity,
     parse_mode::ParseMode,
+    poll::{InputPollOption, PollType},
     reply::ReplyParameters,
     reply_markup::{InlineKeyboardMarkup, ReplyMarkup},
 };
@@ -71,6 +72,37 @@ pub struct DeleteMessageParams {
     pub message_id: i64,
 }
 
+#[derive(Default, Debug, Clone, PartialEq, serde::Serialize, Builder)]
+#[builder(setter(into), default)]
+pub struct DeleteMessagesParams {
+    pub chat_id: i64,
+    /// 1-100 identifiers of messages to delete, messages that can't be found are skipped
+    pub message_ids: Vec<i64>,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, serde::Serialize, Builder)]
+#[builder(setter(into), default)]
+pub struct PinChatMessageParams {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub business_connection_id: Option<String>,
+    pub chat_id: i64,
+    pub message_id: i64,
+    /// Pin silently, notifications are always disabled in channels and private chats
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub disable_notification: Option<bool>,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, serde::Serialize, Builder)]
+#[builder(setter(into), default)]
+pub struct UnpinChatMessageParams {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub business_connection_id: Option<String>,
+    pub chat_id: i64,
+    /// Message to unpin, the most recent pinned message is unpinned if not specified
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message_id: Option<i64>,
+}
+
 #[derive(Default, Debug, Clone, PartialEq, serde::Serialize, Builder)]
 #[builder(setter(into), default)]
 pub struct EditMessageReplyMarkupParams {
@@ -85,3 +117,326 @@ pub struct EditMessageReplyMarkupParams {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub reply_markup: Option<InlineKeyboardMarkup>,
 }
+
+#[derive(Default, Debug, Clone, PartialEq, serde::Serialize, Builder)]
+#[builder(setter(into), default)]
+pub struct ForwardMessageParams {
+    pub chat_id: i64,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message_thread_id: Option<i64>,
+    /// Chat where the original message was sent
+    pub from_chat_id: i64,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub disable_notification: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub protect_content: Option<bool>,
+    pub message_id: i64,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, serde::Serialize, Builder)]
+#[builder(setter(into), default)]
+pub struct ForwardMessagesParams {
+    pub chat_id: i64,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message_thread_id: Option<i64>,
+    pub from_chat_id: i64,
+    /// 1-100 identifiers of messages in the chat `from_chat_id`, in strictly increasing order
+    pub message_ids: Vec<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub disable_notification: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub protect_content: Option<bool>,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, serde::Serialize, Builder)]
+#[builder(setter(into), default)]
+pub struct CopyMessageParams {
+    pub chat_id: i64,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message_thread_id: Option<i64>,
+    pub from_chat_id: i64,
+    pub message_id: i64,
+    /// New caption for media, the original caption is kept if not specified
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub caption: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub parse_mode: Option<ParseMode>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub caption_entities: Option<Vec<MessageEntity>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub show_caption_above_media: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub disable_notification: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub protect_content: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reply_parameters: Option<ReplyParameters>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reply_markup: Option<ReplyMarkup>,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, serde::Serialize, Builder)]
+#[builder(setter(into), default)]
+pub struct CopyMessagesParams {
+    pub chat_id: i64,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message_thread_id: Option<i64>,
+    pub from_chat_id: i64,
+    /// 1-100 identifiers of messages in the chat `from_chat_id`, in strictly increasing order
+    pub message_ids: Vec<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub disable_notification: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub protect_content: Option<bool>,
+    /// Copy the messages without their captions
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub remove_caption: Option<bool>,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, serde::Serialize, Builder)]
+#[builder(setter(into), default)]
+pub struct SendLocationParams {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub business_connection_id: Option<String>,
+    pub chat_id: i64,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message_thread_id: Option<i64>,
+    pub latitude: f64,
+    pub longitude: f64,
+    /// The radius of uncertainty for the location, measured in meters; 0-1500
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub horizontal_accuracy: Option<f64>,
+    /// Period in seconds during which the location will be updated, 60-86400 or 0x7FFFFFFF for live locations that can be edited indefinitely
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub live_period: Option<u64>,
+    /// Direction in which the user is moving, in degrees; 1-360. For live locations only
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub heading: Option<u16>,
+    /// Maximum distance in meters for proximity alerts about approaching another chat member. For live locations only
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub proximity_alert_radius: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub disable_notification: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub protect_content: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message_effect_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reply_parameters: Option<ReplyParameters>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reply_markup: Option<ReplyMarkup>,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, serde::Serialize, Builder)]
+#[builder(setter(into), default)]
+pub struct EditMessageLiveLocationParams {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub business_connection_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub chat_id: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message_id: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub inline_message_id: Option<String>,
+    pub latitude: f64,
+    pub longitude: f64,
+    /// New period in seconds during which the location can be updated, starting from the message send date
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub live_period: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub horizontal_accuracy: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub heading: Option<u16>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub proximity_alert_radius: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reply_markup: Option<InlineKeyboardMarkup>,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, serde::Serialize, Builder)]
+#[builder(setter(into), default)]
+pub struct StopMessageLiveLocationParams {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub business_connection_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub chat_id: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message_id: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub inline_message_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reply_markup: Option<InlineKeyboardMarkup>,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, serde::Serialize, Builder)]
+#[builder(setter(into), default)]
+pub struct SendVenueParams {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub business_connection_id: Option<String>,
+    pub chat_id: i64,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message_thread_id: Option<i64>,
+    pub latitude: f64,
+    pub longitude: f64,
+    pub title: String,
+    pub address: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub foursquare_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub foursquare_type: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub google_place_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub google_place_type: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub disable_notification: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub protect_content: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message_effect_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reply_parameters: Option<ReplyParameters>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reply_markup: Option<ReplyMarkup>,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, serde::Serialize, Builder)]
+#[builder(setter(into), default)]
+pub struct SendContactParams {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub business_connection_id: Option<String>,
+    pub chat_id: i64,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message_thread_id: Option<i64>,
+    pub phone_number: String,
+    pub first_name: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub last_name: Option<String>,
+    /// Additional data about the contact in the form of a vCard, 0-2048 bytes
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub vcard: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub disable_notification: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub protect_content: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message_effect_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reply_parameters: Option<ReplyParameters>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reply_markup: Option<ReplyMarkup>,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, serde::Serialize, Builder)]
+#[builder(setter(into), default)]
+pub struct SendDiceParams {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub business_connection_id: Option<String>,
+    pub chat_id: i64,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message_thread_id: Option<i64>,
+    /// Emoji on which the dice throw animation is based, one of 🎲, 🎯, 🏀, ⚽, 🎳 or 🎰. Defaults to 🎲
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub emoji: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub disable_notification: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub protect_content: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message_effect_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reply_parameters: Option<ReplyParameters>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reply_markup: Option<ReplyMarkup>,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, serde::Serialize, Builder)]
+#[builder(setter(into), default)]
+pub struct SendPollParams {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub business_connection_id: Option<String>,
+    pub chat_id: i64,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message_thread_id: Option<i64>,
+    /// Poll question, 1-300 characters
+    pub question: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub question_parse_mode: Option<ParseMode>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub question_entities: Option<Vec<MessageEntity>>,
+    /// 2-10 answer options
+    pub options: Vec<InputPollOption>,
+    /// True by default
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub is_anonymous: Option<bool>,
+    #[serde(rename = "type", skip_serializing_if = "Option::is_none")]
+    pub poll_type: Option<PollType>,
+    /// Ignored for polls in quiz mode
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub allows_multiple_answers: Option<bool>,
+    /// 0-based identifier of the correct answer option, required for polls in quiz mode
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub correct_option_id: Option<u8>,
+    /// Text shown when a user chooses an incorrect answer in a quiz, 0-200 characters
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub explanation: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub explanation_parse_mode: Option<ParseMode>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub explanation_entities: Option<Vec<MessageEntity>>,
+    /// Amount of time in seconds the poll will be active after creation, 5-600. Can't be used together with close_date
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub open_period: Option<u64>,
+    /// Unix timestamp when the poll will be automatically closed
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub close_date: Option<u64>,
+    /// Pass true to close the poll immediately
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub is_closed: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub disable_notification: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub protect_content: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message_effect_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reply_parameters: Option<ReplyParameters>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reply_markup: Option<ReplyMarkup>,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, serde::Serialize, Builder)]
+#[builder(setter(into), default)]
+pub struct StopPollParams {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub business_connection_id: Option<String>,
+    pub chat_id: i64,
+    pub message_id: i64,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reply_markup: Option<InlineKeyboardMarkup>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_quiz_params() {
+        let params = SendPollParamsBuilder::default()
+            .chat_id(1)
+            .question("2 + 2 = ?")
+            .options(vec![InputPollOption::from("4"), InputPollOption::from("5")])
+            .poll_type(PollType::Quiz)
+            .correct_option_id(0)
+            .open_period(30)
+            .build()
+            .unwrap();
+        let json = serde_json::to_value(&params).unwrap();
+        assert_eq!(json["type"], "quiz");
+        assert_eq!(
+            json["options"],
+            serde_json::json!([{"text": "4"}, {"text": "5"}])
+        );
+        assert!(json.get("is_anonymous").is_none());
+    }
+}
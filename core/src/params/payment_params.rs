@@ -0,0 +1,232 @@
+use derive_builder::Builder;
+use serde::Serialize;
+
+use crate::models::{
+    reply::ReplyParameters,
+    reply_markup::InlineKeyboardMarkup,
+    shipping_query::{LabeledPrice, ShippingOption},
+};
+
+/// Currency of payments in Telegram Stars, they need no provider token.
+pub const TELEGRAM_STARS: &str = "XTR";
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Builder)]
+#[builder(setter(into), default)]
+pub struct SendInvoiceParams {
+    pub chat_id: i64,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message_thread_id: Option<i64>,
+    /// Product name, 1-32 characters
+    pub title: String,
+    /// Product description, 1-255 characters
+    pub description: String,
+    /// Bot-defined invoice payload, 1-128 bytes. Not displayed to the user, use it for your internal processes
+    pub payload: String,
+    /// Payment provider token, omit it for payments in Telegram Stars
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub provider_token: Option<String>,
+    /// Three-letter ISO 4217 currency code, `TELEGRAM_STARS` for payments in Telegram Stars
+    pub currency: String,
+    /// Price breakdown, exactly one item for payments in Telegram Stars
+    pub prices: Vec<LabeledPrice>,
+    /// Maximum accepted tip in the smallest units of the currency, not supported for Telegram Stars
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_tip_amount: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub suggested_tip_amounts: Option<Vec<u64>>,
+    /// JSON data about the invoice shared with the payment provider
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub provider_data: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub photo_url: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub photo_size: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub photo_width: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub photo_height: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub need_name: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub need_phone_number: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub need_email: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub need_shipping_address: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub send_phone_number_to_provider: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub send_email_to_provider: Option<bool>,
+    /// The final price depends on the shipping method, a shipping query is sent to the bot
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub is_flexible: Option<bool>,
+    /// Deep-linking parameter, forwarded copies of the message have a Pay button linking to the bot with it
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub start_parameter: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub disable_notification: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub protect_content: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message_effect_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reply_parameters: Option<ReplyParameters>,
+    /// The first button must be a Pay button
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reply_markup: Option<InlineKeyboardMarkup>,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Builder)]
+#[builder(setter(into), default)]
+pub struct CreateInvoiceLinkParams {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub business_connection_id: Option<String>,
+    /// Product name, 1-32 characters
+    pub title: String,
+    /// Product description, 1-255 characters
+    pub description: String,
+    /// Bot-defined invoice payload, 1-128 bytes. Not displayed to the user, use it for your internal processes
+    pub payload: String,
+    /// Payment provider token, omit it for payments in Telegram Stars
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub provider_token: Option<String>,
+    /// Three-letter ISO 4217 currency code, `TELEGRAM_STARS` for payments in Telegram Stars
+    pub currency: String,
+    /// Price breakdown, exactly one item for payments in Telegram Stars
+    pub prices: Vec<LabeledPrice>,
+    /// Seconds the subscription is active for before the next payment, currently always 2592000. Telegram Stars only
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub subscription_period: Option<u64>,
+    /// Maximum accepted tip in the smallest units of the currency, not supported for Telegram Stars
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_tip_amount: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub suggested_tip_amounts: Option<Vec<u64>>,
+    /// JSON data about the invoice shared with the payment provider
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub provider_data: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub photo_url: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub photo_size: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub photo_width: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub photo_height: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub need_name: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub need_phone_number: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub need_email: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub need_shipping_address: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub send_phone_number_to_provider: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub send_email_to_provider: Option<bool>,
+    /// The final price depends on the shipping method, a shipping query is sent to the bot
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub is_flexible: Option<bool>,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Builder)]
+#[builder(setter(into), default)]
+pub struct AnswerShippingQueryParams {
+    pub shipping_query_id: String,
+    /// True if delivery to the address is possible
+    pub ok: bool,
+    /// Required if ok is true
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub shipping_options: Option<Vec<ShippingOption>>,
+    /// Required if ok is false, explains why the order can't be delivered
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error_message: Option<String>,
+}
+
+impl AnswerShippingQueryParams {
+    /// Deliver to the address with one of the `shipping_options`.
+    pub fn ok(shipping_query_id: impl Into<String>, shipping_options: Vec<ShippingOption>) -> Self {
+        Self {
+            shipping_query_id: shipping_query_id.into(),
+            ok: true,
+            shipping_options: Some(shipping_options),
+            error_message: None,
+        }
+    }
+
+    /// Refuse the address, `error_message` is shown to the user.
+    pub fn error(shipping_query_id: impl Into<String>, error_message: impl Into<String>) -> Self {
+        Self {
+            shipping_query_id: shipping_query_id.into(),
+            ok: false,
+            shipping_options: None,
+            error_message: Some(error_message.into()),
+        }
+    }
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Builder)]
+#[builder(setter(into), default)]
+pub struct AnswerPreCheckoutQueryParams {
+    pub pre_checkout_query_id: String,
+    /// True if the goods are available and the bot is ready to proceed with the order
+    pub ok: bool,
+    /// Required if ok is false, explains why the checkout can't proceed
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error_message: Option<String>,
+}
+
+impl AnswerPreCheckoutQueryParams {
+    /// Confirm the order.
+    pub fn ok(pre_checkout_query_id: impl Into<String>) -> Self {
+        Self {
+            pre_checkout_query_id: pre_checkout_query_id.into(),
+            ok: true,
+            error_message: None,
+        }
+    }
+
+    /// Cancel the order, `error_message` is shown to the user.
+    pub fn error(
+        pre_checkout_query_id: impl Into<String>,
+        error_message: impl Into<String>,
+    ) -> Self {
+        Self {
+            pre_checkout_query_id: pre_checkout_query_id.into(),
+            ok: false,
+            error_message: Some(error_message.into()),
+        }
+    }
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Builder)]
+#[builder(setter(into), default)]
+pub struct RefundStarPaymentParams {
+    pub user_id: u64,
+    pub telegram_payment_charge_id: String,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_star_invoice_has_no_provider_token() {
+        let params = SendInvoiceParamsBuilder::default()
+            .chat_id(1)
+            .title("Premium")
+            .description("30 days of premium")
+            .payload("order-42")
+            .currency(TELEGRAM_STARS)
+            .prices(vec![LabeledPrice {
+                label: "Premium".to_string(),
+                amount: 250,
+            }])
+            .build()
+            .unwrap();
+        let json = serde_json::to_value(&params).unwrap();
+        assert!(json.get("provider_token").is_none());
+        assert_eq!(json["currency"], "XTR");
+    }
+}
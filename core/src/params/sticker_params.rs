@@ -0,0 +1,246 @@
+use derive_builder::Builder;
+use serde::Serialize;
+
+use crate::models::{
+    input_file::{FileUpload, InputFile, InputFiles},
+    mask_position::MaskPosition,
+    sticker::{InputSticker, StickerFormat, StickerType},
+};
+
+#[derive(Debug, Clone, Serialize, PartialEq, Builder)]
+pub struct GetStickerSetParams {
+    /// Short name of the sticker set
+    #[builder(setter(into))]
+    pub name: String,
+}
+
+#[derive(Debug, Clone, Serialize, PartialEq, Builder)]
+pub struct GetCustomEmojiStickersParams {
+    /// At most 200 custom emoji identifiers
+    #[builder(setter(into))]
+    pub custom_emoji_ids: Vec<String>,
+}
+
+/// Upload a sticker file to use it in several sticker set methods.
+#[derive(Debug, Clone, Serialize, PartialEq, Builder)]
+pub struct UploadStickerFileParams {
+    /// Owner of the sticker set the file is uploaded for
+    #[builder(setter(into))]
+    pub user_id: u64,
+    #[builder(setter(into))]
+    pub sticker: InputFile,
+    #[builder(setter(into))]
+    pub sticker_format: StickerFormat,
+}
+
+impl InputFiles for UploadStickerFileParams {
+    fn input_files(&self) -> Vec<&InputFile> {
+        self.sticker.input_files()
+    }
+}
+
+#[derive(Debug, Clone, Serialize, PartialEq, Builder)]
+pub struct CreateNewStickerSetParams {
+    /// Owner of the created sticker set
+    #[builder(setter(into))]
+    pub user_id: u64,
+    /// Short name used in t.me/addstickers/ urls, it must end with `_by_<bot_username>`
+    #[builder(setter(into))]
+    pub name: String,
+    /// Sticker set title, 1-64 characters
+    #[builder(setter(into))]
+    pub title: String,
+    /// 1-50 initial stickers
+    #[builder(setter(into))]
+    pub stickers: Vec<InputSticker>,
+    /// Regular stickers by default
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[builder(setter(into, strip_option), default)]
+    pub sticker_type: Option<StickerType>,
+    /// Custom emoji repainted to the text color in messages, for custom emoji sticker sets only
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[builder(setter(into, strip_option), default)]
+    pub needs_repainting: Option<bool>,
+}
+
+impl InputFiles for CreateNewStickerSetParams {
+    fn input_files(&self) -> Vec<&InputFile> {
+        self.stickers.input_files()
+    }
+}
+
+#[derive(Debug, Clone, Serialize, PartialEq, Builder)]
+pub struct AddStickerToSetParams {
+    #[builder(setter(into))]
+    pub user_id: u64,
+    /// Short name of the sticker set
+    #[builder(setter(into))]
+    pub name: String,
+    #[builder(setter(into))]
+    pub sticker: InputSticker,
+}
+
+impl InputFiles for AddStickerToSetParams {
+    fn input_files(&self) -> Vec<&InputFile> {
+        self.sticker.input_files()
+    }
+}
+
+#[derive(Debug, Clone, Serialize, PartialEq, Builder)]
+pub struct SetStickerPositionInSetParams {
+    /// File identifier of the sticker
+    #[builder(setter(into))]
+    pub sticker: String,
+    /// New zero-based position in the set
+    #[builder(setter(into))]
+    pub position: u64,
+}
+
+#[derive(Debug, Clone, Serialize, PartialEq, Builder)]
+pub struct DeleteStickerFromSetParams {
+    /// File identifier of the sticker
+    #[builder(setter(into))]
+    pub sticker: String,
+}
+
+#[derive(Debug, Clone, Serialize, PartialEq, Builder)]
+pub struct ReplaceStickerInSetParams {
+    #[builder(setter(into))]
+    pub user_id: u64,
+    /// Short name of the sticker set
+    #[builder(setter(into))]
+    pub name: String,
+    /// File identifier of the replaced sticker
+    #[builder(setter(into))]
+    pub old_sticker: String,
+    #[builder(setter(into))]
+    pub sticker: InputSticker,
+}
+
+impl InputFiles for ReplaceStickerInSetParams {
+    fn input_files(&self) -> Vec<&InputFile> {
+        self.sticker.input_files()
+    }
+}
+
+#[derive(Debug, Clone, Serialize, PartialEq, Builder)]
+pub struct SetStickerEmojiListParams {
+    #[builder(setter(into))]
+    pub sticker: String,
+    /// 1-20 emoji associated with the sticker
+    #[builder(setter(into))]
+    pub emoji_list: Vec<String>,
+}
+
+#[derive(Debug, Clone, Serialize, PartialEq, Builder)]
+pub struct SetStickerKeywordsParams {
+    #[builder(setter(into))]
+    pub sticker: String,
+    /// 0-20 search keywords, the keywords are removed if not specified
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[builder(setter(into, strip_option), default)]
+    pub keywords: Option<Vec<String>>,
+}
+
+#[derive(Debug, Clone, Serialize, PartialEq, Builder)]
+pub struct SetStickerMaskPositionParams {
+    #[builder(setter(into))]
+    pub sticker: String,
+    /// The mask position is removed if not specified
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[builder(setter(into, strip_option), default)]
+    pub mask_position: Option<MaskPosition>,
+}
+
+#[derive(Debug, Clone, Serialize, PartialEq, Builder)]
+pub struct SetStickerSetTitleParams {
+    /// Short name of the sticker set
+    #[builder(setter(into))]
+    pub name: String,
+    /// Sticker set title, 1-64 characters
+    #[builder(setter(into))]
+    pub title: String,
+}
+
+#[derive(Debug, Clone, Serialize, PartialEq, Builder)]
+pub struct SetStickerSetThumbnailParams {
+    /// Short name of the sticker set
+    #[builder(setter(into))]
+    pub name: String,
+    #[builder(setter(into))]
+    pub user_id: u64,
+    /// The first sticker is used as the thumbnail if not specified
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[builder(setter(into, strip_option), default)]
+    pub thumbnail: Option<FileUpload>,
+    /// Format of the thumbnail
+    #[builder(setter(into))]
+    pub format: StickerFormat,
+}
+
+impl InputFiles for SetStickerSetThumbnailParams {
+    fn input_files(&self) -> Vec<&InputFile> {
+        self.thumbnail.input_files()
+    }
+}
+
+#[derive(Debug, Clone, Serialize, PartialEq, Builder)]
+pub struct SetCustomEmojiStickerSetThumbnailParams {
+    /// Short name of the sticker set
+    #[builder(setter(into))]
+    pub name: String,
+    /// The first sticker is used as the thumbnail if not specified
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[builder(setter(into, strip_option), default)]
+    pub custom_emoji_id: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, PartialEq, Builder)]
+pub struct DeleteStickerSetParams {
+    /// Short name of the sticker set
+    #[builder(setter(into))]
+    pub name: String,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_create_sticker_set_files() {
+        let sticker = InputFile::memory("cat.webp", vec![0u8; 4]);
+        let params = CreateNewStickerSetParamsBuilder::default()
+            .user_id(1u64)
+            .name("cats_by_test_bot")
+            .title("Cats")
+            .stickers(vec![
+                InputSticker {
+                    sticker: sticker.clone().into(),
+                    format: StickerFormat::Static,
+                    emoji_list: vec!["🐱".to_string()],
+                    mask_position: None,
+                    keywords: None,
+                },
+                InputSticker {
+                    sticker: FileUpload::file_id("CAACAgIAAxkBAAI"),
+                    format: StickerFormat::Static,
+                    emoji_list: vec!["😺".to_string()],
+                    mask_position: None,
+                    keywords: None,
+                },
+            ])
+            .build()
+            .unwrap();
+        assert_eq!(params.input_files(), vec![&sticker]);
+
+        let json = serde_json::to_value(&params).unwrap();
+        assert_eq!(
+            json["stickers"][0],
+            serde_json::json!({
+                "sticker": format!("attach://{}", sticker.attach_name()),
+                "format": "static",
+                "emoji_list": ["🐱"],
+            })
+        );
+    }
+}
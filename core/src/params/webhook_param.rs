@@ -1,4 +1,7 @@
-use crate::models::{allowed_update::AllowedUpdate, sticker::InputFile};
+use crate::models::{
+    allowed_update::AllowedUpdate,
+    input_file::{InputFile, InputFiles},
+};
 use derive_builder::Builder;
 use serde::{Deserialize, Serialize};
 
@@ -20,6 +23,12 @@ pub struct SetWebhookParams {
     pub secret_token: Option<String>,
 }
 
+impl InputFiles for SetWebhookParams {
+    fn input_files(&self) -> Vec<&InputFile> {
+        self.certificate.input_files()
+    }
+}
+
 #[derive(Debug, Default, Clone, Serialize, Deserialize, PartialEq, Builder)]
 #[builder(setter(into), default)]
 pub struct DeleteWebhookParams {
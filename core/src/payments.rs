@@ -0,0 +1,399 @@
+use std::collections::HashMap;
+use std::future::Future;
+use std::pin::Pin;
+use std::time::Duration;
+
+use tokio::time::Instant;
+
+use crate::{
+    bot::Bot,
+    models::{
+        message::Message,
+        pre_check_query::PreCheckoutQuery,
+        shipping_query::{ShippingOption, ShippingQuery},
+        successful_payment::SuccessfulPayment,
+        update::UpdateContent,
+    },
+    params::payment_params::{AnswerPreCheckoutQueryParams, AnswerShippingQueryParams},
+    requests::RetryPolicy,
+    JsonData, TelegrapherError, TelegrapherResult,
+};
+
+/// Shipping validator type, returns the shipping options for the address,
+/// or the error message shown to the user when the order can't be delivered.
+pub type ShippingValidator =
+    fn(
+        Bot,
+        ShippingQuery,
+    ) -> Pin<Box<dyn Future<Output = Result<Vec<ShippingOption>, String>> + Send>>;
+
+/// Pre-checkout validator type, returns the error message shown to the user when the order can't proceed.
+pub type PreCheckoutValidator =
+    fn(Bot, PreCheckoutQuery) -> Pin<Box<dyn Future<Output = Result<(), String>> + Send>>;
+
+/// Fulfilment handler type, called with the service message of a successful payment.
+pub type FulfilmentHandler =
+    fn(
+        Bot,
+        Message,
+        SuccessfulPayment,
+    ) -> Pin<Box<dyn Future<Output = TelegrapherResult<Option<JsonData>>> + Send>>;
+
+/// Telegram waits 10 seconds for the answer to a shipping or pre-checkout query. The answer is sent within
+/// this time from the moment the flow gets the query, leaving a margin for the network.
+pub const DEFAULT_ANSWER_DEADLINE: Duration = Duration::from_secs(9);
+
+/// How long a validator may run, the rest of the deadline is kept for sending the answer.
+pub const DEFAULT_VALIDATOR_TIMEOUT: Duration = Duration::from_secs(7);
+
+/// Drives the payment updates of a bot: shipping and pre-checkout queries are answered with the
+/// result of the validators, successful payments are delivered to the fulfilment handler of their
+/// invoice payload.
+///
+/// A validator that fails, panics or doesn't finish within the timeout is answered with `ok: false`
+/// and the default error message. The answer itself, retries included, is sent before the deadline,
+/// so the query is always answered before telegram gives up on it.
+/// Register it with `Bot::register_payment_flow`.
+#[derive(Debug, Clone)]
+pub struct PaymentFlow {
+    shipping_validator: Option<ShippingValidator>,
+    pre_checkout_validator: Option<PreCheckoutValidator>,
+    fulfilment_handlers: HashMap<String, FulfilmentHandler>,
+    default_fulfilment_handler: Option<FulfilmentHandler>,
+    timeout: Duration,
+    deadline: Duration,
+    error_message: String,
+}
+
+impl Default for PaymentFlow {
+    fn default() -> Self {
+        Self {
+            shipping_validator: None,
+            pre_checkout_validator: None,
+            fulfilment_handlers: HashMap::new(),
+            default_fulfilment_handler: None,
+            timeout: DEFAULT_VALIDATOR_TIMEOUT,
+            deadline: DEFAULT_ANSWER_DEADLINE,
+            error_message: "Sorry, the order can't be processed right now. Please try again later."
+                .to_string(),
+        }
+    }
+}
+
+impl PaymentFlow {
+    /// Validate the shipping address of invoices with a flexible price.
+    /// Without a validator every shipping query is refused.
+    pub fn with_shipping_validator(mut self, validator: ShippingValidator) -> Self {
+        self.shipping_validator = Some(validator);
+        self
+    }
+
+    /// Check the order before the payment, e.g. that the goods are still in stock.
+    /// Without a validator every pre-checkout query is confirmed.
+    pub fn with_pre_checkout_validator(mut self, validator: PreCheckoutValidator) -> Self {
+        self.pre_checkout_validator = Some(validator);
+        self
+    }
+
+    /// Deliver the successful payments of invoices with this payload.
+    pub fn with_fulfilment_handler(
+        mut self,
+        invoice_payload: impl Into<String>,
+        handler: FulfilmentHandler,
+    ) -> Self {
+        self.fulfilment_handlers
+            .insert(invoice_payload.into(), handler);
+        self
+    }
+
+    /// Deliver the successful payments with a payload no other fulfilment handler is registered for.
+    pub fn with_default_fulfilment_handler(mut self, handler: FulfilmentHandler) -> Self {
+        self.default_fulfilment_handler = Some(handler);
+        self
+    }
+
+    /// How long a validator may run, keep it well below the deadline.
+    pub fn with_timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = timeout;
+        self
+    }
+
+    /// Time from getting a query to sending its answer, keep it below 10 seconds.
+    pub fn with_deadline(mut self, deadline: Duration) -> Self {
+        self.deadline = deadline;
+        self
+    }
+
+    /// Error message shown to the user when a validator fails or times out.
+    pub fn with_error_message(mut self, error_message: impl Into<String>) -> Self {
+        self.error_message = error_message.into();
+        self
+    }
+
+    /// The fulfilment handler of an invoice payload.
+    pub fn fulfilment_handler(&self, invoice_payload: &str) -> Option<FulfilmentHandler> {
+        self.fulfilment_handlers
+            .get(invoice_payload)
+            .copied()
+            .or(self.default_fulfilment_handler)
+    }
+
+    /// Handle a payment update, `None` if the update is not handled by the payment flow.
+    pub async fn process_update(
+        &self,
+        bot: &Bot,
+        content: &UpdateContent,
+    ) -> Option<TelegrapherResult<Option<JsonData>>> {
+        match content {
+            UpdateContent::ShippingQuery(query) => {
+                Some(self.answer_shipping_query(bot, query).await.map(|_| None))
+            }
+            UpdateContent::PreCheckoutQuery(query) => Some(
+                self.answer_pre_checkout_query(bot, query)
+                    .await
+                    .map(|_| None),
+            ),
+            UpdateContent::Message(message) => {
+                let payment = message.successful_payment.as_deref()?;
+                let handler = self.fulfilment_handler(&payment.invoice_payload)?;
+                Some(handler(bot.clone(), message.clone(), payment.clone()).await)
+            }
+            _ => None,
+        }
+    }
+
+    /// Run the shipping validator and answer the query with its result.
+    pub async fn answer_shipping_query(
+        &self,
+        bot: &Bot,
+        query: &ShippingQuery,
+    ) -> TelegrapherResult<bool> {
+        let deadline = Instant::now() + self.deadline;
+        let result = match self.shipping_validator {
+            Some(validator) => {
+                self.validate(&query.id, deadline, validator(bot.clone(), query.clone()))
+                    .await
+            }
+            None => Err(self.error_message.clone()),
+        };
+        let params = match result {
+            Ok(shipping_options) => AnswerShippingQueryParams::ok(&query.id, shipping_options),
+            Err(error_message) => AnswerShippingQueryParams::error(&query.id, error_message),
+        };
+        self.send_answer(bot, &query.id, deadline, |bot| async move {
+            bot.answer_shipping_query(&params).await
+        })
+        .await
+    }
+
+    /// Run the pre-checkout validator and answer the query with its result.
+    pub async fn answer_pre_checkout_query(
+        &self,
+        bot: &Bot,
+        query: &PreCheckoutQuery,
+    ) -> TelegrapherResult<bool> {
+        let deadline = Instant::now() + self.deadline;
+        let result = match self.pre_checkout_validator {
+            Some(validator) => {
+                self.validate(&query.id, deadline, validator(bot.clone(), query.clone()))
+                    .await
+            }
+            None => Ok(()),
+        };
+        let params = match result {
+            Ok(()) => AnswerPreCheckoutQueryParams::ok(&query.id),
+            Err(error_message) => AnswerPreCheckoutQueryParams::error(&query.id, error_message),
+        };
+        self.send_answer(bot, &query.id, deadline, |bot| async move {
+            bot.answer_pre_checkout_query(&params).await
+        })
+        .await
+    }
+
+    /// Run a validator in its own task, a panic or timeout becomes the default error message.
+    async fn validate<T, F>(
+        &self,
+        query_id: &str,
+        deadline: Instant,
+        validator: F,
+    ) -> Result<T, String>
+    where
+        T: Send + 'static,
+        F: Future<Output = Result<T, String>> + Send + 'static,
+    {
+        let until = deadline.min(Instant::now() + self.timeout);
+        let mut task = tokio::spawn(validator);
+        match tokio::time::timeout_at(until, &mut task).await {
+            Ok(Ok(result)) => result,
+            Ok(Err(e)) => {
+                log::error!("payment validator of query {} failed: {}", query_id, e);
+                Err(self.error_message.clone())
+            }
+            Err(_) => {
+                task.abort();
+                log::warn!(
+                    "payment validator of query {} timed out after {:?}",
+                    query_id,
+                    self.timeout
+                );
+                Err(self.error_message.clone())
+            }
+        }
+    }
+
+    /// Send the answer of a query with the time left until the deadline:
+    /// every attempt and every wait between the attempts ends by then.
+    async fn send_answer<F, Fut>(
+        &self,
+        bot: &Bot,
+        query_id: &str,
+        deadline: Instant,
+        answer: F,
+    ) -> TelegrapherResult<bool>
+    where
+        F: FnOnce(Bot) -> Fut,
+        Fut: Future<Output = TelegrapherResult<bool>>,
+    {
+        let time_left = deadline.saturating_duration_since(Instant::now());
+        let bot = bot.clone().with_retry_policy(RetryPolicy {
+            base_delay: Duration::from_millis(100),
+            max_delay: time_left,
+            attempt_timeout: Some(time_left),
+            ..Default::default()
+        });
+        match tokio::time::timeout_at(deadline, answer(bot)).await {
+            Ok(result) => result,
+            Err(_) => {
+                log::error!("answer of payment query {} missed the deadline", query_id);
+                Err(TelegrapherError::Other(format!(
+                    "answer of payment query {} missed the deadline of {:?}",
+                    query_id, self.deadline
+                )))
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::{Arc, Mutex};
+
+    use axum::{extract::Path, routing::post, Json, Router};
+    use serde_json::{json, Value};
+
+    use super::*;
+
+    /// Bot API stand-in, records the params of every request.
+    async fn start_api_server(requests: Arc<Mutex<Vec<(String, Value)>>>) -> String {
+        let app = Router::new().route(
+            "/:token/:method",
+            post(
+                move |Path((_, method)): Path<(String, String)>, Json(params): Json<Value>| async move {
+                    requests.lock().unwrap().push((method, params));
+                    Json(json!({"ok": true, "result": true}))
+                },
+            ),
+        );
+        crate::test_server::serve(app).await
+    }
+
+    fn pre_checkout_query(invoice_payload: &str) -> PreCheckoutQuery {
+        serde_json::from_value(json!({
+            "id": "q1",
+            "from": {"id": 1, "is_bot": false, "first_name": "a"},
+            "currency": "XTR",
+            "total_amount": 250,
+            "invoice_payload": invoice_payload,
+        }))
+        .unwrap()
+    }
+
+    fn check_stock(
+        _: Bot,
+        query: PreCheckoutQuery,
+    ) -> Pin<Box<dyn Future<Output = Result<(), String>> + Send>> {
+        Box::pin(async move {
+            match query.invoice_payload.as_str() {
+                "in-stock" => Ok(()),
+                "slow" => {
+                    tokio::time::sleep(Duration::from_secs(5)).await;
+                    Ok(())
+                }
+                _ => Err("Out of stock".to_string()),
+            }
+        })
+    }
+
+    fn fulfil(
+        _: Bot,
+        _: Message,
+        _: SuccessfulPayment,
+    ) -> Pin<Box<dyn Future<Output = TelegrapherResult<Option<JsonData>>> + Send>> {
+        Box::pin(async { Ok(None) })
+    }
+
+    #[tokio::test]
+    async fn test_pre_checkout_answers() {
+        let requests = Arc::new(Mutex::new(Vec::new()));
+        let bot = Bot::new("123:abc", 1).with_api_url(&start_api_server(requests.clone()).await);
+        let flow = PaymentFlow::default()
+            .with_pre_checkout_validator(check_stock)
+            .with_timeout(Duration::from_millis(100))
+            .with_error_message("Try again");
+
+        for payload in ["in-stock", "sold-out", "slow"] {
+            let update = UpdateContent::PreCheckoutQuery(pre_checkout_query(payload));
+            flow.process_update(&bot, &update).await.unwrap().unwrap();
+        }
+
+        let requests = requests.lock().unwrap();
+        let answers: Vec<&Value> = requests.iter().map(|(_, params)| params).collect();
+        assert!(requests
+            .iter()
+            .all(|(method, _)| method == "answerPreCheckoutQuery"));
+        assert_eq!(
+            answers[0],
+            &json!({"pre_checkout_query_id": "q1", "ok": true})
+        );
+        assert_eq!(
+            answers[1],
+            &json!({"pre_checkout_query_id": "q1", "ok": false, "error_message": "Out of stock"})
+        );
+        assert_eq!(
+            answers[2],
+            &json!({"pre_checkout_query_id": "q1", "ok": false, "error_message": "Try again"})
+        );
+    }
+
+    #[tokio::test]
+    async fn test_stalled_answer_keeps_the_deadline() {
+        let app = Router::new().route(
+            "/:token/:method",
+            post(|| async {
+                tokio::time::sleep(Duration::from_secs(30)).await;
+                Json(json!({"ok": true, "result": true}))
+            }),
+        );
+        let bot = Bot::new("123:abc", 1).with_api_url(&crate::test_server::serve(app).await);
+        let flow = PaymentFlow::default()
+            .with_pre_checkout_validator(check_stock)
+            .with_timeout(Duration::from_millis(100))
+            .with_deadline(Duration::from_millis(300));
+
+        let started = std::time::Instant::now();
+        let update = UpdateContent::PreCheckoutQuery(pre_checkout_query("slow"));
+        let result = flow.process_update(&bot, &update).await.unwrap();
+        assert!(result.is_err());
+        assert!(started.elapsed() < Duration::from_secs(1));
+    }
+
+    #[test]
+    fn test_fulfilment_handler() {
+        let flow = PaymentFlow::default().with_fulfilment_handler("premium", fulfil);
+        assert!(flow.fulfilment_handler("premium").is_some());
+        assert!(flow.fulfilment_handler("stickers").is_none());
+
+        let flow = flow.with_default_fulfilment_handler(fulfil);
+        assert!(flow.fulfilment_handler("stickers").is_some());
+    }
+}
@@ -1,114 +1,385 @@
-use std::fmt::Display;
-use std::{fmt::Debug, path::PathBuf, time::Duration};
+use std::{
+    fmt::Debug,
+    future::Future,
+    time::{Duration, Instant},
+};
 
-use reqwest::multipart::{self, Part};
+use reqwest::multipart;
+use reqwest::StatusCode;
 use serde::de::DeserializeOwned;
 use serde_json::Value;
 
-use crate::{responses::MethodResponse, FileType, TelegrapherError, TELEGRAM_API_URL};
+use crate::{
+    bot::Bot,
+    middleware::{ApiRequest, ApiResponse},
+    models::input_file::InputFiles,
+    responses::MethodResponse,
+    TelegrapherError,
+};
 
-/// post a normal http request to the telegram api
+/// Default http client settings used by `Bot::new`.
+/// Start from this builder to add a proxy, user agent or pool settings, then pass the client to `Bot::with_client`.
+pub fn default_client_builder() -> reqwest::ClientBuilder {
+    reqwest::Client::builder()
+        .connect_timeout(Duration::from_secs(5))
+        .timeout(Duration::from_secs(17))
+}
+
+/// How a failed request is repeated.
+/// Flood control (429) waits for `retry_after`, server errors (5xx) and connect errors
+/// wait with an exponential backoff: `base_delay * 2^(attempt - 1)`, capped by `max_delay`.
+///
+/// Set it for all requests with `Bot::with_retry_policy`, or for a single call with
+/// `bot.clone().with_retry_policy(policy).send_photo(&params)`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct RetryPolicy {
+    /// Total attempts including the first one, 1 disables retrying.
+    pub max_attempts: u32,
+    pub base_delay: Duration,
+    /// Longest wait between two attempts. A `retry_after` longer than this is not waited for.
+    pub max_delay: Duration,
+    /// Randomize the backoff delay between half and the full delay.
+    pub jitter: bool,
+    /// Retry 429 Too Many Requests after `retry_after` seconds.
+    pub retry_on_flood: bool,
+    /// Retry 5xx responses.
+    pub retry_on_server_error: bool,
+    /// Retry connect errors, the request never reached telegram.
+    pub retry_on_network_error: bool,
+    /// Retry requests that timed out. Off by default: telegram may have received and executed the request,
+    /// so retrying `sendMessage`, `sendInvoice`, `copyMessage` and other methods that are not idempotent
+    /// can send duplicated messages.
+    pub retry_on_timeout: bool,
+    /// Timeout of each attempt, replacing the timeout of the bot's http client.
+    pub attempt_timeout: Option<Duration>,
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self {
+            max_attempts: 3,
+            base_delay: Duration::from_millis(500),
+            max_delay: Duration::from_secs(30),
+            jitter: true,
+            retry_on_flood: true,
+            retry_on_server_error: true,
+            retry_on_network_error: true,
+            retry_on_timeout: false,
+            attempt_timeout: None,
+        }
+    }
+}
+
+impl RetryPolicy {
+    /// Never retry, every request is sent once.
+    pub fn none() -> Self {
+        Self {
+            max_attempts: 1,
+            ..Default::default()
+        }
+    }
+
+    /// Exponential backoff delay before the next attempt, `attempt` starts from 1.
+    pub fn backoff(&self, attempt: u32) -> Duration {
+        let factor = 2u32.saturating_pow(attempt.saturating_sub(1));
+        let delay = self.base_delay.saturating_mul(factor).min(self.max_delay);
+        if self.jitter {
+            delay.mul_f64(0.5 + fastrand::f64() / 2.0)
+        } else {
+            delay
+        }
+    }
+
+    /// Delay before repeating a request that failed at `attempt`, `None` if it should not be repeated.
+    fn retry_delay(
+        &self,
+        attempt: u32,
+        status: Option<StatusCode>,
+        error: &TelegrapherError,
+    ) -> Option<Duration> {
+        if attempt >= self.max_attempts {
+            return None;
+        }
+        match error {
+            TelegrapherError::Api(e) if e.error_code == 429 => {
+                let retry_after = Duration::from_secs(e.retry_after()?);
+                (self.retry_on_flood && retry_after <= self.max_delay).then_some(retry_after)
+            }
+            TelegrapherError::Api(e) if e.error_code >= 500 => {
+                self.retry_on_server_error.then(|| self.backoff(attempt))
+            }
+            TelegrapherError::Json { .. } if status.is_some_and(|s| s.is_server_error()) => {
+                self.retry_on_server_error.then(|| self.backoff(attempt))
+            }
+            TelegrapherError::Network(e) if e.is_connect() => {
+                self.retry_on_network_error.then(|| self.backoff(attempt))
+            }
+            TelegrapherError::Network(e) if e.is_timeout() => {
+                self.retry_on_timeout.then(|| self.backoff(attempt))
+            }
+            _ => None,
+        }
+    }
+}
+
+/// post a normal http request to the telegram api, returns the `result` of the response.
+/// A response with `ok: false` is returned as `TelegrapherError::Api`.
 pub async fn post_request<P, T>(
+    bot: &Bot,
     method: &str,
-    token: &str,
     params: Option<&P>,
-) -> Result<MethodResponse<T>, TelegrapherError>
+) -> Result<T, TelegrapherError>
 where
     P: serde::ser::Serialize + std::fmt::Debug + std::marker::Send,
     T: DeserializeOwned + Debug,
 {
-    let api_base_url = reqwest::Url::parse(TELEGRAM_API_URL)
-        .expect("failed to parse default Telegram bot API url");
-    let url = format!("{}bot{}/{}", api_base_url, token, method);
-    let client = reqwest::Client::builder()
-        .connect_timeout(Duration::from_secs(5))
-        .timeout(Duration::from_secs(17))
-        .build()
-        .expect("failed to create reqwest::Client");
+    post_raw_request(bot, method, params).await?.into_result()
+}
 
-    let mut prepared_request = client
-        .post(url.clone())
-        .header("Content-Type", "application/json");
+/// post a normal http request to the telegram api, returns the raw response.
+pub async fn post_raw_request<P, T>(
+    bot: &Bot,
+    method: &str,
+    params: Option<&P>,
+) -> Result<MethodResponse<T>, TelegrapherError>
+where
+    P: serde::ser::Serialize + std::fmt::Debug + std::marker::Send,
+    T: DeserializeOwned + Debug,
+{
+    let params = match params {
+        Some(data) => serde_json::to_value(data).map_err(TelegrapherError::Encode)?,
+        None => Value::Null,
+    };
 
-    prepared_request = if let Some(data) = params {
-        let json_string = encode_params(&data)?;
+    send_with_retry(bot, method, params, true, |request| async move {
+        let prepared_request = bot
+            .client
+            .post(bot.method_url(&request.method))
+            .header("Content-Type", "application/json");
+        let params = &request.params;
+        if params.is_null() {
+            return Ok(prepared_request);
+        }
+        let json_string = encode_params(params)?;
         log::debug!("send data json string: {}", json_string);
-        prepared_request.body(json_string)
+        Ok(prepared_request.body(json_string))
+    })
+    .await
+}
+
+/// Post the params with their input files as a multipart request, returns the `result` of the response.
+/// Params without any new file to upload are sent as json.
+pub async fn post_multi_part_request<P, T>(
+    bot: &Bot,
+    method: &str,
+    params: &P,
+) -> Result<T, TelegrapherError>
+where
+    P: serde::ser::Serialize + InputFiles + std::fmt::Debug + std::marker::Send,
+    T: DeserializeOwned + Debug,
+{
+    post_raw_multi_part_request(bot, method, params)
+        .await?
+        .into_result()
+}
+
+/// Post the params with their input files as a multipart request, returns the raw response.
+/// Every input file is a part named by its attach name, the other fields are text parts.
+pub async fn post_raw_multi_part_request<P, T>(
+    bot: &Bot,
+    method: &str,
+    params: &P,
+) -> Result<MethodResponse<T>, TelegrapherError>
+where
+    P: serde::ser::Serialize + InputFiles + std::fmt::Debug + std::marker::Send,
+    T: DeserializeOwned + Debug,
+{
+    let input_files = params.input_files();
+    if input_files.is_empty() {
+        return post_raw_request(bot, method, Some(params)).await;
+    }
+
+    let params = serde_json::to_value(params).map_err(TelegrapherError::Encode)?;
+    // a stream can be uploaded only once
+    let replayable = input_files.iter().all(|file| file.is_replayable());
+    let bot = if replayable {
+        bot.clone()
     } else {
-        prepared_request
+        let mut policy = (*bot.retry_policy).clone();
+        policy.max_attempts = 1;
+        bot.clone().with_retry_policy(policy)
     };
 
-    let response_str = prepared_request.send().await?.text().await?;
-    let response: MethodResponse<T> = serde_json::from_str(&response_str)?;
-    Ok(response)
+    let (bot, input_files) = (&bot, &input_files);
+    send_with_retry(bot, method, params, replayable, |request| async move {
+        let mut form = multipart::Form::new();
+        if let Some(fields) = request.params.as_object() {
+            for (key, val) in fields {
+                let val = match val {
+                    Value::Null => continue,
+                    Value::String(val) => val.to_string(),
+                    other => other.to_string(),
+                };
+                form = form.text(key.clone(), val);
+            }
+        }
+        for input_file in input_files {
+            form = form.part(
+                input_file.attach_name().to_string(),
+                input_file.to_part().await?,
+            );
+        }
+        Ok(bot
+            .client
+            .post(bot.method_url(&request.method))
+            .multipart(form))
+    })
+    .await
 }
 
-/// Post multi part request to the telegram api
-pub async fn post_multi_part_request<P, T>(
+/// Send the request built by `build_request`, and send it again as long as the retry policy of the bot allows.
+/// When the target group has been migrated to a supergroup, `chat_id` in the params is replaced with the new
+/// chat id, the chat migration handler is notified and the request is sent again. A request that is not
+/// `replayable`, because it uploads a reader, is not sent again: the migration error is returned as is.
+/// The middlewares of the bot see the request before it is sent and the response of every attempt.
+async fn send_with_retry<T, F, Fut>(
+    bot: &Bot,
     method: &str,
-    token: &str,
-    params: Option<&P>,
-    file_path: &PathBuf,
-    file_type: &FileType,
+    params: Value,
+    replayable: bool,
+    build_request: F,
 ) -> Result<MethodResponse<T>, TelegrapherError>
 where
-    P: serde::ser::Serialize + std::fmt::Debug + std::marker::Send,
-    T: DeserializeOwned + Debug,
+    T: DeserializeOwned,
+    F: Fn(ApiRequest) -> Fut,
+    Fut: Future<Output = Result<reqwest::RequestBuilder, TelegrapherError>>,
 {
-    let api_base_url = reqwest::Url::parse(TELEGRAM_API_URL)
-        .expect("failed to parse default Telegram bot API url");
-    let url = format!("{}bot{}/{}", api_base_url, token, method);
-    let client = reqwest::Client::builder()
-        .connect_timeout(Duration::from_secs(5))
-        .timeout(Duration::from_secs(17))
-        .build()
-        .expect("failed to create reqwest::Client");
-
-    let json_string = encode_params(&params)?;
-    let json_struct: Value = serde_json::from_str(&json_string).unwrap();
-    let filename = file_path.file_name().ok_or_else(|| {
-        TelegrapherError::from("file path is not valid. please provide a valid path.")
-    })?;
-    let filename_str = filename.to_str().ok_or_else(|| {
-        TelegrapherError::from("file path is not valid. Please provide a valid path.")
-    })?;
-    let mut form = multipart::Form::new();
-    for (key, val) in json_struct.as_object().unwrap() {
-        if !key.as_str().eq(file_type.to_string().as_str()) {
-            let val = match val {
-                Value::String(val) => val.to_string(),
-                other => other.to_string(),
-            };
-
-            form = form.text(key.clone(), val);
+    let mut request = ApiRequest {
+        method: method.to_string(),
+        params,
+    };
+    for middleware in bot.middlewares.iter() {
+        middleware.before_request(&mut request).await?;
+    }
+
+    let mut attempt = 1;
+    let mut migrated = false;
+    loop {
+        let started = Instant::now();
+        let mut prepared_request = build_request(request.clone()).await?;
+        if let Some(timeout) = bot.retry_policy.attempt_timeout {
+            prepared_request = prepared_request.timeout(timeout);
+        }
+        let (status, body, result) = match prepared_request.send().await {
+            Ok(response) => {
+                let status = response.status();
+                match response.text().await {
+                    Ok(body) => {
+                        let result = decode_response::<T>(&body);
+                        (Some(status), Some(body), result)
+                    }
+                    Err(e) => (Some(status), None, Err(TelegrapherError::from(e))),
+                }
+            }
+            Err(e) => (None, None, Err(TelegrapherError::from(e))),
+        };
+
+        let api_error = result
+            .as_ref()
+            .ok()
+            .and_then(|response| response.api_error())
+            .map(TelegrapherError::Api);
+        let error = match (&result, &api_error) {
+            (Err(error), _) | (Ok(_), Some(error)) => Some(error),
+            _ => None,
+        };
+        let response = ApiResponse {
+            attempt,
+            elapsed: started.elapsed(),
+            status,
+            body: body.as_deref(),
+            error,
+        };
+        for middleware in bot.middlewares.iter() {
+            middleware.after_response(&request, &response).await;
+        }
+        let Some(error) = error else {
+            return result;
+        };
+
+        let migrate_to_chat_id = match error {
+            TelegrapherError::Api(e) => e.migrate_to_chat_id(),
+            _ => None,
+        };
+        if let (Some(new_chat_id), false) = (migrate_to_chat_id, migrated) {
+            if let Some(old_chat_id) = replace_chat_id(&mut request.params, new_chat_id) {
+                bot.notify_chat_migration(old_chat_id, new_chat_id).await;
+                if !replayable {
+                    log::warn!(
+                        "chat {} migrated to {}, {} can't be resent",
+                        old_chat_id,
+                        new_chat_id,
+                        request.method
+                    );
+                    return result;
+                }
+                log::info!(
+                    "chat {} migrated to {}, resending {}",
+                    old_chat_id,
+                    new_chat_id,
+                    request.method
+                );
+                migrated = true;
+                continue;
+            }
+        }
+
+        match bot.retry_policy.retry_delay(attempt, status, error) {
+            Some(delay) => {
+                log::warn!(
+                    "{} failed at attempt {}: {}, retrying in {:?}",
+                    request.method,
+                    attempt,
+                    error,
+                    delay
+                );
+                tokio::time::sleep(delay).await;
+                attempt += 1;
+            }
+            None => return result,
         }
     }
-    form = form.part(
-        file_type.to_string(),
-        Part::stream(reqwest::Body::from(std::fs::read(file_path)?))
-            .file_name(filename_str.to_string()),
-    );
-    let res = client
-        .post(&url)
-        .multipart(form)
-        .send()
-        .await?
-        .text()
-        .await?;
-    let response: MethodResponse<T> = serde_json::from_str(&res)?;
-    Ok(response)
+}
+
+/// Replace the numeric `chat_id` of the params, returns the old chat id.
+fn replace_chat_id(params: &mut Value, new_chat_id: i64) -> Option<i64> {
+    let chat_id = params.get_mut("chat_id")?;
+    let old_chat_id = chat_id.as_i64()?;
+    *chat_id = Value::from(new_chat_id);
+    Some(old_chat_id)
 }
 
 /// Encode the params to json string
 pub fn encode_params<T: serde::ser::Serialize + Debug>(
     params: &T,
 ) -> Result<String, TelegrapherError> {
-    serde_json::to_string(params).map_err(TelegrapherError::from)
+    serde_json::to_string(params).map_err(TelegrapherError::Encode)
+}
+
+/// Decode the response body, the raw body is kept in the error if it is not valid json
+fn decode_response<T: DeserializeOwned>(body: &str) -> Result<MethodResponse<T>, TelegrapherError> {
+    match serde_json::from_str(body) {
+        Ok(response) => Ok(response),
+        Err(source) => Err(TelegrapherError::Json {
+            source,
+            body: body.to_string(),
+        }),
+    }
 }
 
 #[cfg(test)]
 mod tests {
-    use crate::{bot::Bot, models::user::User, params::message_params::SendMessageParams};
+    use crate::{models::user::User, params::message_params::SendMessageParams};
 
     use super::*;
 
@@ -123,10 +394,269 @@ mod tests {
         println!("{}", encoded);
     }
 
+    #[test]
+    fn test_encode_error() {
+        let params = std::collections::HashMap::from([((1, 2), "map keys must be strings")]);
+        assert!(matches!(
+            encode_params(&params),
+            Err(TelegrapherError::Encode(_))
+        ));
+    }
+
+    #[test]
+    fn test_decode_response_keeps_body() {
+        let body = "<html>Bad Gateway</html>".to_string();
+        let result = decode_response::<User>(&body);
+        match result {
+            Err(TelegrapherError::Json { body: raw, .. }) => assert_eq!(raw, body),
+            other => panic!("unexpected result: {:?}", other),
+        }
+    }
+
     #[tokio::test]
     async fn test_do_request() {
         let bot = Bot::new("6616659571:AAEr0TdwPXBnvHQl_VJj5Z6wh-p3uUDNbOw", 1);
-        let result = post_request::<String, User>("getMe", bot.token(), None).await;
+        let result = post_request::<String, User>(&bot, "getMe", None).await;
         assert!(result.is_ok());
     }
+
+    #[test]
+    fn test_retry_policy_backoff() {
+        let policy = RetryPolicy {
+            jitter: false,
+            ..Default::default()
+        };
+        assert_eq!(policy.backoff(1), Duration::from_millis(500));
+        assert_eq!(policy.backoff(3), Duration::from_secs(2));
+        assert_eq!(policy.backoff(20), Duration::from_secs(30));
+
+        let jittered = RetryPolicy::default().backoff(2);
+        assert!(jittered >= Duration::from_millis(500) && jittered <= Duration::from_secs(1));
+    }
+
+    #[test]
+    fn test_retry_policy_delay() {
+        let policy = RetryPolicy {
+            jitter: false,
+            ..Default::default()
+        };
+        let flood = TelegrapherError::Api(crate::ApiError {
+            error_code: 429,
+            description: "Too Many Requests: retry after 3".to_string(),
+            parameters: Some(crate::responses::ResponseParameters {
+                migrate_to_chat_id: None,
+                retry_after: Some(3),
+            }),
+        });
+        assert_eq!(
+            policy.retry_delay(1, Some(StatusCode::TOO_MANY_REQUESTS), &flood),
+            Some(Duration::from_secs(3))
+        );
+        assert_eq!(policy.retry_delay(3, None, &flood), None);
+        assert_eq!(RetryPolicy::none().retry_delay(1, None, &flood), None);
+
+        let bad_gateway = decode_response::<User>("<html>Bad Gateway</html>").unwrap_err();
+        assert_eq!(
+            policy.retry_delay(2, Some(StatusCode::BAD_GATEWAY), &bad_gateway),
+            Some(Duration::from_secs(1))
+        );
+
+        let bad_request = TelegrapherError::Api(crate::ApiError {
+            error_code: 400,
+            description: "Bad Request: chat not found".to_string(),
+            parameters: None,
+        });
+        assert_eq!(policy.retry_delay(1, None, &bad_request), None);
+    }
+
+    #[tokio::test]
+    async fn test_timeout_is_not_retried_by_default() {
+        use std::sync::{
+            atomic::{AtomicUsize, Ordering},
+            Arc,
+        };
+
+        use axum::{routing::post, Json, Router};
+
+        let requests = Arc::new(AtomicUsize::new(0));
+        let counter = requests.clone();
+        let app = Router::new().route(
+            "/:token/:method",
+            post(move || async move {
+                counter.fetch_add(1, Ordering::SeqCst);
+                tokio::time::sleep(Duration::from_secs(30)).await;
+                Json(serde_json::json!({"ok": true, "result": true}))
+            }),
+        );
+        let api_url = crate::test_server::serve(app).await;
+        let policy = RetryPolicy {
+            base_delay: Duration::from_millis(10),
+            attempt_timeout: Some(Duration::from_millis(100)),
+            ..Default::default()
+        };
+
+        let bot = Bot::new("123:abc", 1)
+            .with_api_url(&api_url)
+            .with_retry_policy(policy.clone());
+        let result = post_request::<(), bool>(&bot, "sendMessage", None).await;
+        assert!(matches!(result, Err(TelegrapherError::Network(e)) if e.is_timeout()));
+        assert_eq!(requests.load(Ordering::SeqCst), 1);
+
+        let bot = bot.with_retry_policy(RetryPolicy {
+            retry_on_timeout: true,
+            ..policy
+        });
+        _ = post_request::<(), bool>(&bot, "sendMessage", None).await;
+        assert_eq!(requests.load(Ordering::SeqCst), 4);
+    }
+
+    #[tokio::test]
+    async fn test_reader_upload_keeps_attempt_timeout() {
+        use crate::{
+            models::{input_file::InputFile, message::Message},
+            params::media_params::SendDocumentParamsBuilder,
+        };
+        use axum::{routing::post, Json, Router};
+
+        let app = Router::new().route(
+            "/:token/:method",
+            post(|| async {
+                tokio::time::sleep(Duration::from_secs(30)).await;
+                Json(serde_json::json!({"ok": true, "result": true}))
+            }),
+        );
+        let bot = Bot::new("123:abc", 1)
+            .with_api_url(&crate::test_server::serve(app).await)
+            .with_retry_policy(RetryPolicy {
+                attempt_timeout: Some(Duration::from_millis(100)),
+                ..Default::default()
+            });
+
+        let params = SendDocumentParamsBuilder::default()
+            .chat_id(1)
+            .document(InputFile::reader(
+                "report.csv",
+                std::io::Cursor::new(b"a,b\n1,2\n"),
+                None,
+            ))
+            .build()
+            .unwrap();
+        let started = Instant::now();
+        let result = post_multi_part_request::<_, Message>(&bot, "sendDocument", &params).await;
+        assert!(matches!(result, Err(TelegrapherError::Network(e)) if e.is_timeout()));
+        assert!(started.elapsed() < Duration::from_secs(5));
+    }
+
+    #[test]
+    fn test_replace_chat_id() {
+        let mut params = serde_json::json!({"chat_id": -4123, "text": "hello"});
+        assert_eq!(replace_chat_id(&mut params, -1004123), Some(-4123));
+        assert_eq!(params["chat_id"], -1004123);
+
+        let mut params = serde_json::json!({"inline_message_id": "abc"});
+        assert_eq!(replace_chat_id(&mut params, -1004123), None);
+    }
+
+    #[tokio::test]
+    async fn test_multi_part_request_with_several_files() {
+        use crate::{
+            models::{input_file::InputFile, message::Message},
+            params::media_params::SendDocumentParamsBuilder,
+        };
+        use axum::{routing::post, Json, Router};
+
+        // echoes the raw multipart body back in the message text
+        let app = Router::new().route(
+            "/:token/:method",
+            post(|body: String| async move {
+                Json(serde_json::json!({
+                    "ok": true,
+                    "result": {"message_id": 1, "date": 0, "chat": {"id": 1, "type": "private"}, "text": body}
+                }))
+            }),
+        );
+        let bot = Bot::new("123:abc", 1).with_api_url(&crate::test_server::serve(app).await);
+
+        let document = InputFile::memory("report.csv", "a,b\n1,2\n");
+        let thumbnail = InputFile::memory("thumb.jpg", "jpeg bytes");
+        let params = SendDocumentParamsBuilder::default()
+            .chat_id(1)
+            .document(document.clone())
+            .thumbnail(thumbnail.clone())
+            .build()
+            .unwrap();
+        let message: Message = post_multi_part_request(&bot, "sendDocument", &params)
+            .await
+            .unwrap();
+        let body = message.text.unwrap();
+        assert!(body.contains(&format!(
+            "name=\"{}\"; filename=\"report.csv\"",
+            document.attach_name()
+        )));
+        assert!(body.contains(&format!(
+            "name=\"{}\"; filename=\"thumb.jpg\"",
+            thumbnail.attach_name()
+        )));
+        assert!(body.contains(&format!("attach://{}", thumbnail.attach_name())));
+        assert!(body.contains("a,b\n1,2\n") && body.contains("jpeg bytes"));
+    }
+
+    #[tokio::test]
+    async fn test_migrated_reader_upload_is_not_resent() {
+        use std::sync::{
+            atomic::{AtomicUsize, Ordering},
+            Arc,
+        };
+
+        use crate::{
+            models::{input_file::InputFile, message::Message},
+            params::media_params::SendDocumentParamsBuilder,
+        };
+        use axum::{routing::post, Json, Router};
+
+        let requests = Arc::new(AtomicUsize::new(0));
+        let counter = requests.clone();
+        let app = Router::new().route(
+            "/:token/:method",
+            post(move || async move {
+                counter.fetch_add(1, Ordering::SeqCst);
+                Json(serde_json::json!({
+                    "ok": false,
+                    "error_code": 400,
+                    "description": "Bad Request: group chat was upgraded to a supergroup chat",
+                    "parameters": {"migrate_to_chat_id": -1004123}
+                }))
+            }),
+        );
+        let bot = Bot::new("123:abc", 1).with_api_url(&crate::test_server::serve(app).await);
+
+        let params = SendDocumentParamsBuilder::default()
+            .chat_id(-4123)
+            .document(InputFile::reader(
+                "report.csv",
+                std::io::Cursor::new(b"a,b\n1,2\n"),
+                None,
+            ))
+            .build()
+            .unwrap();
+        let result = post_multi_part_request::<_, Message>(&bot, "sendDocument", &params).await;
+        match result {
+            Err(TelegrapherError::Api(e)) => assert_eq!(e.migrate_to_chat_id(), Some(-1004123)),
+            other => panic!("unexpected result: {:?}", other),
+        }
+        assert_eq!(requests.load(Ordering::SeqCst), 1);
+    }
+
+    #[test]
+    fn test_custom_api_url() {
+        let bot = Bot::new("123:abc", 1).with_api_url("http://localhost:8081/");
+        assert_eq!(
+            bot.method_url("getMe"),
+            "http://localhost:8081/bot123:abc/getMe"
+        );
+        assert_eq!(
+            bot.file_url("photos/file_0.jpg"),
+            "http://localhost:8081/file/bot123:abc/photos/file_0.jpg"
+        );
+    }
 }
@@ -1,6 +1,6 @@
 use serde::{Deserialize, Serialize};
 
-use crate::{JsonData, TelegrapherResult};
+use crate::{ApiError, JsonData, TelegrapherError, TelegrapherResult};
 
 #[derive(Debug, Serialize, Deserialize, PartialEq)]
 pub struct MethodResponse<T> {
@@ -11,6 +11,30 @@ pub struct MethodResponse<T> {
     pub parameters: Option<ResponseParameters>,
 }
 
+impl<T> MethodResponse<T> {
+    /// The error described by the response, `None` if `ok` is true.
+    pub fn api_error(&self) -> Option<ApiError> {
+        if self.ok {
+            return None;
+        }
+        Some(ApiError {
+            error_code: self.error_code.unwrap_or_default(),
+            description: self.description.clone().unwrap_or_default(),
+            parameters: self.parameters,
+        })
+    }
+
+    /// Turn the response into the `result`, or into `TelegrapherError::Api` if `ok` is false.
+    pub fn into_result(self) -> TelegrapherResult<T> {
+        if let Some(error) = self.api_error() {
+            return Err(TelegrapherError::Api(error));
+        }
+        self.result.ok_or_else(|| {
+            TelegrapherError::Other("response is ok but the result is missing".to_string())
+        })
+    }
+}
+
 #[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq)]
 pub struct ResponseParameters {
     pub migrate_to_chat_id: Option<i64>,
@@ -21,8 +45,10 @@ pub fn build_webhook_response<T: Serialize>(
     method: &str,
     params: T,
 ) -> TelegrapherResult<JsonData> {
-    let mut json = serde_json::to_value(params)?;
-    let json_object = json.as_object_mut().ok_or("Not an object")?;
+    let mut json = serde_json::to_value(params).map_err(TelegrapherError::Encode)?;
+    let json_object = json
+        .as_object_mut()
+        .ok_or_else(|| TelegrapherError::InvalidParams("params is not an object".to_string()))?;
     json_object.insert(
         "method".to_string(),
         serde_json::Value::String(method.to_string()),
@@ -47,4 +73,24 @@ mod tests {
         let result = build_webhook_response(method, params);
         print!("{:?}", result);
     }
+
+    #[test]
+    fn test_into_result() {
+        let response: MethodResponse<bool> =
+            serde_json::from_str(r#"{"ok":true,"result":true}"#).unwrap();
+        assert!(response.into_result().unwrap());
+
+        let response: MethodResponse<bool> = serde_json::from_str(
+            r#"{"ok":false,"error_code":429,"description":"Too Many Requests: retry after 5","parameters":{"retry_after":5}}"#,
+        )
+        .unwrap();
+        match response.into_result() {
+            Err(TelegrapherError::Api(e)) => {
+                assert_eq!(e.error_code, 429);
+                assert_eq!(e.retry_after(), Some(5));
+                assert_eq!(e.migrate_to_chat_id(), None);
+            }
+            other => panic!("unexpected result: {:?}", other),
+        }
+    }
 }
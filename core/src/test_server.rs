@@ -0,0 +1,9 @@
+use axum::Router;
+
+/// Serve `app` on a free local port as a Bot API stand-in, returns the base url to pass to `Bot::with_api_url`.
+pub(crate) async fn serve(app: Router) -> String {
+    let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let addr = listener.local_addr().unwrap();
+    tokio::spawn(async move { axum::serve(listener, app).await });
+    format!("http://{}", addr)
+}
@@ -1,4 +1,4 @@
-use core::{bot::Bot, models::sticker::FileUpload};
+use core::{bot::Bot, models::input_file::FileUpload};
 use telegrapher::params::media_params::SendDocumentParamsBuilder;
 
 #[tokio::main]
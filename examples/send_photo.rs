@@ -1,6 +1,6 @@
 use core::{
     bot::Bot,
-    models::sticker::{FileUpload, InputFile},
+    models::input_file::InputFile,
     params::media_params::SendPhotoParamsBuilder,
 };
 
@@ -11,9 +11,7 @@ async fn main() {
     // let result = bot.send_photo("1393242628", photo_path).await;
     let send_photo_params = SendPhotoParamsBuilder::default()
         .chat_id(1393242628)
-        .photo(FileUpload::InputFile(InputFile {
-            path: photo_path.to_path_buf(),
-        }))
+        .photo(InputFile::path(photo_path))
         .build()
         .unwrap();
     // let send_photo_params = SendPhotoParamsBuilder::default()
@@ -23,7 +23,7 @@ async fn main() {
     tokio::time::sleep(Duration::from_secs(5)).await;
 
     let arc_bot = Arc::new(bot);
-    for i in 0..1000 {
+    for _ in 0..1000 {
         let my_bot = arc_bot.clone();
         // tokio::time::sleep(Duration::from_secs_f32(0.1)).await;
         let chat_id = 1393242628;
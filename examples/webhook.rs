@@ -56,7 +56,7 @@ async fn command_handler(
 }
 
 #[event_handler]
-async fn update_handler(bot: Bot, update: UpdateContent) -> TelegrapherResult<Option<JsonData>> {
+async fn update_handler(_bot: Bot, update: UpdateContent) -> TelegrapherResult<Option<JsonData>> {
     println!("{:?}", update);
     let method = "sendMessage";
     let params = SendMessageParamsBuilder::default()